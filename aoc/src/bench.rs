@@ -0,0 +1,47 @@
+use crate::with_solution;
+use common::{ParseError, Solution};
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+// mean runtimes over every run, parts that weren't benchmarked are `None`
+#[derive(Debug, Clone, Copy)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Option<Duration>,
+    pub part2: Option<Duration>,
+}
+
+// times parsing `input` and answering each of `parts` for the day, `runs` times each
+pub fn bench(day: u8, parts: &[u8], input: &str, runs: u32) -> Result<Timings, String> {
+    let Some(timings) = with_solution!(day, time(parts, input, runs.max(1))) else {
+        return Err(format!("no solution for day {}", day));
+    };
+    timings.map_err(|e| format!("couldn't parse day {} input: {}", day, e))
+}
+
+fn time<S: Solution>(parts: &[u8], input: &str, runs: u32) -> Result<Timings, ParseError> {
+    let parsed = S::parse(input)?;
+    let parse = mean(runs, || drop(black_box(S::parse(black_box(input)))));
+
+    let time_part = |part: u8, solve: fn(&S::Input) -> S::Output| {
+        parts
+            .contains(&part)
+            .then(|| mean(runs, || drop(black_box(solve(black_box(&parsed))))))
+    };
+
+    Ok(Timings {
+        parse,
+        part1: time_part(1, S::part1),
+        part2: time_part(2, S::part2),
+    })
+}
+
+fn mean(runs: u32, mut f: impl FnMut()) -> Duration {
+    let start = Instant::now();
+    for _ in 0..runs {
+        f();
+    }
+    start.elapsed() / runs
+}
@@ -0,0 +1,59 @@
+mod bench;
+pub use bench::{bench, Timings};
+
+use common::{ParseError, Solution};
+use std::{
+    ops::RangeInclusive,
+    path::{Path, PathBuf},
+};
+
+pub const DAYS: RangeInclusive<u8> = 1..=15;
+
+// calls the generic `$f` with the day's `Solution`, `None` for days without one
+macro_rules! with_solution {
+    ($day:expr, $f:ident($($arg:expr),*)) => {
+        match $day {
+            1 => Some($f::<day_1::Day1>($($arg),*)),
+            2 => Some($f::<day_2::Day2>($($arg),*)),
+            3 => Some($f::<day_3::Day3>($($arg),*)),
+            4 => Some($f::<day_4::Day4>($($arg),*)),
+            5 => Some($f::<day_5::Day5>($($arg),*)),
+            6 => Some($f::<day_6::Day6>($($arg),*)),
+            7 => Some($f::<day_7::Day7>($($arg),*)),
+            8 => Some($f::<day_8::Day8>($($arg),*)),
+            9 => Some($f::<day_9::Day9>($($arg),*)),
+            10 => Some($f::<day_10::Day10>($($arg),*)),
+            11 => Some($f::<day_11::Day11>($($arg),*)),
+            12 => Some($f::<day_12::Day12>($($arg),*)),
+            13 => Some($f::<day_13::Day13>($($arg),*)),
+            14 => Some($f::<day_14::Day14>($($arg),*)),
+            15 => Some($f::<day_15::Day15>($($arg),*)),
+            _ => None,
+        }
+    };
+}
+pub(crate) use with_solution;
+
+// the `resources` dir of a day's crate, where its input and samples live
+pub fn resources_dir(day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(format!("day_{}", day))
+        .join("resources")
+}
+
+pub fn solve(day: u8, part: u8, input: &str) -> Result<String, String> {
+    let Some(answer) = with_solution!(day, run(part, input)) else {
+        return Err(format!("no solution for day {}", day));
+    };
+    answer.map_err(|e| format!("couldn't parse day {} input: {}", day, e))
+}
+
+fn run<S: Solution>(part: u8, input: &str) -> Result<String, ParseError> {
+    let parsed = S::parse(input)?;
+    let answer = match part {
+        1 => S::part1(&parsed).to_string(),
+        _ => S::part2(&parsed).to_string(),
+    };
+    Ok(answer)
+}
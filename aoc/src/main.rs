@@ -0,0 +1,193 @@
+use aoc::{bench, resources_dir, solve, Timings, DAYS};
+use std::{env, fs, path::Path, process, slice::Iter, time::Duration};
+
+const USAGE: &str = "usage: aoc run <day> [--part 1|2] [--input <path>]
+       aoc bench [<day>...] [--part 1|2] [--runs <n>]";
+
+const DEFAULT_RUNS: u32 = 10;
+
+enum Command {
+    Run(RunArgs),
+    Bench(BenchArgs),
+}
+
+struct RunArgs {
+    day: u8,
+    part: Option<u8>,
+    input: Option<String>,
+}
+
+struct BenchArgs {
+    days: Vec<u8>,
+    part: Option<u8>,
+    runs: u32,
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let command = match parse_args(&args) {
+        Ok(command) => command,
+        Err(msg) => {
+            eprintln!("{}\n{}", msg, USAGE);
+            process::exit(2);
+        }
+    };
+
+    match command {
+        Command::Run(run_args) => run(run_args),
+        Command::Bench(bench_args) => run_benches(bench_args),
+    }
+}
+
+fn parts(part: Option<u8>) -> Vec<u8> {
+    match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    }
+}
+
+fn run(run_args: RunArgs) {
+    let default_path = resources_dir(run_args.day).join("input.txt");
+    let input = match common::input::load(run_args.input.as_deref().map(Path::new), &default_path) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("couldn't read input: {}", e);
+            process::exit(1);
+        }
+    };
+
+    for part in parts(run_args.part) {
+        match solve(run_args.day, part, &input) {
+            Ok(answer) => println!("day {} part {}: {}", run_args.day, part, answer),
+            Err(msg) => {
+                eprintln!("{}", msg);
+                process::exit(1);
+            }
+        }
+    }
+}
+
+// times every day on its real input and prints a row per day as it finishes
+fn run_benches(bench_args: BenchArgs) {
+    let parts = parts(bench_args.part);
+    println!(
+        "mean of {} run(s) on each day's resources/input.txt",
+        bench_args.runs
+    );
+    println!(
+        "{:>3}  {:>12}  {:>12}  {:>12}  {:>12}",
+        "day", "parse", "part 1", "part 2", "total"
+    );
+
+    for day in bench_args.days {
+        let input = match fs::read_to_string(resources_dir(day).join("input.txt")) {
+            Ok(input) => input,
+            Err(e) => {
+                println!("{:>3}  couldn't read input: {}", day, e);
+                continue;
+            }
+        };
+        match bench(day, &parts, &input, bench_args.runs) {
+            Ok(timings) => println!("{:>3}  {}", day, format_timings(&timings)),
+            Err(msg) => println!("{:>3}  {}", day, msg),
+        }
+    }
+}
+
+fn format_timings(timings: &Timings) -> String {
+    let total: Duration =
+        timings.parse + timings.part1.unwrap_or_default() + timings.part2.unwrap_or_default();
+    let fmt = |d: Option<Duration>| match d {
+        Some(d) => format!("{:.2?}", d),
+        None => "-".to_string(),
+    };
+    format!(
+        "{:>12}  {:>12}  {:>12}  {:>12}",
+        fmt(Some(timings.parse)),
+        fmt(timings.part1),
+        fmt(timings.part2),
+        fmt(Some(total))
+    )
+}
+
+fn parse_args(args: &[String]) -> Result<Command, String> {
+    let mut iter = args.iter();
+    match iter.next().map(String::as_str) {
+        Some("run") => parse_run_args(iter).map(Command::Run),
+        Some("bench") => parse_bench_args(iter).map(Command::Bench),
+        Some(other) => Err(format!("unknown command '{}'", other)),
+        None => Err("missing command".to_string()),
+    }
+}
+
+fn parse_day(d: &str) -> Result<u8, String> {
+    d.parse().map_err(|_| format!("invalid day '{}'", d))
+}
+
+fn parse_part(value: Option<&String>) -> Result<Option<u8>, String> {
+    match value.map(String::as_str) {
+        Some("1") => Ok(Some(1)),
+        Some("2") => Ok(Some(2)),
+        Some(other) => Err(format!("invalid part '{}'", other)),
+        None => Err("missing value for --part".to_string()),
+    }
+}
+
+fn parse_run_args(mut iter: Iter<String>) -> Result<RunArgs, String> {
+    let day: u8 = match iter.next() {
+        Some(d) => parse_day(d)?,
+        None => return Err("missing day".to_string()),
+    };
+
+    let mut run_args = RunArgs {
+        day,
+        part: None,
+        input: None,
+    };
+
+    while let Some(flag) = iter.next() {
+        match flag.as_str() {
+            "--part" => run_args.part = parse_part(iter.next())?,
+            "--input" => {
+                run_args.input = match iter.next() {
+                    Some(path) => Some(path.to_string()),
+                    None => return Err("missing value for --input".to_string()),
+                }
+            }
+            other => return Err(format!("unknown argument '{}'", other)),
+        }
+    }
+
+    Ok(run_args)
+}
+
+// with no days given every day is benchmarked
+fn parse_bench_args(mut iter: Iter<String>) -> Result<BenchArgs, String> {
+    let mut bench_args = BenchArgs {
+        days: vec![],
+        part: None,
+        runs: DEFAULT_RUNS,
+    };
+
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--part" => bench_args.part = parse_part(iter.next())?,
+            "--runs" => {
+                bench_args.runs = match iter.next() {
+                    Some(n) => match n.parse() {
+                        Ok(0) | Err(_) => return Err(format!("invalid run count '{}'", n)),
+                        Ok(runs) => runs,
+                    },
+                    None => return Err("missing value for --runs".to_string()),
+                }
+            }
+            day if !day.starts_with("--") => bench_args.days.push(parse_day(day)?),
+            other => return Err(format!("unknown argument '{}'", other)),
+        }
+    }
+
+    if bench_args.days.is_empty() {
+        bench_args.days = DAYS.collect();
+    }
+    Ok(bench_args)
+}
@@ -0,0 +1,86 @@
+// inputs that used to parse and then panic while solving are rejected as parse errors instead
+fn rejects(day: u8, input: &str, reason: &str) {
+    for part in [1, 2] {
+        match aoc::solve(day, part, input) {
+            Err(msg) => assert!(msg.contains(reason), "day {}: {}", day, msg),
+            Ok(answer) => panic!("day {} part {} answered {}", day, part, answer),
+        }
+    }
+}
+
+#[test]
+fn day_3_numbers_too_big() {
+    rejects(
+        3,
+        "..99999999999*\n..............",
+        "line 1, column 3: expected a number",
+    );
+}
+
+#[test]
+fn day_4_too_many_matches() {
+    let nums = (1..=40)
+        .map(|n| n.to_string())
+        .collect::<Vec<_>>()
+        .join(" ");
+    rejects(
+        4,
+        &format!("Card 1: {} | {}\n", nums, nums),
+        "line 1, column 8: more than 32 matches in",
+    );
+}
+
+#[test]
+fn day_6_lines_without_numbers() {
+    rejects(6, "Time:\nDistance:\n", "line 1, column 1: no numbers in");
+}
+
+#[test]
+fn day_6_unkerned_number_too_big() {
+    rejects(
+        6,
+        "Time: 9999999999 9999999999\nDistance: 1 1\n",
+        "line 1, column 7: expected one number without the spaces, found",
+    );
+}
+
+#[test]
+fn day_9_sequences_too_short() {
+    rejects(
+        9,
+        "0 3 6\n7\n",
+        "line 2, column 1: expected a sequence of at least two values",
+    );
+}
+
+#[test]
+fn day_5_without_seeds() {
+    rejects(
+        5,
+        "seeds: \n\nseed-to-soil map:\n50 98 2\n",
+        "line 1, column 1: no seeds in",
+    );
+}
+
+#[test]
+fn day_5_empty_seed_range() {
+    rejects(
+        5,
+        "seeds: 79 0\n\nseed-to-soil map:\n50 70 20\n",
+        "line 1, column 1: empty seed range in",
+    );
+}
+
+#[test]
+fn day_12_empty_groups() {
+    rejects(12, "???.### 1,0,3\n", "line 1, column 11: empty group '0'");
+}
+
+#[test]
+fn day_13_pattern_without_a_mirror_line() {
+    rejects(
+        13,
+        "#.\n.#\n",
+        "line 1, column 1: no mirror line with 0 smudges in pattern starting '#.'",
+    );
+}
@@ -0,0 +1,48 @@
+use std::{fs, panic, path::PathBuf};
+
+// every day's `resources/sample*.txt` is solved and checked against the
+// `sample*.part1.expected` and `sample*.part2.expected` files next to it, parts without an
+// expected file are skipped
+#[test]
+fn samples_match_expected_answers() {
+    let mut checked = 0;
+    let mut failures: Vec<String> = vec![];
+
+    for day in aoc::DAYS {
+        for sample in sample_files(day) {
+            let input = fs::read_to_string(&sample).expect("read sample");
+            for part in [1, 2] {
+                let expected_path = sample.with_extension(format!("part{}.expected", part));
+                let Ok(expected) = fs::read_to_string(&expected_path) else {
+                    continue;
+                };
+                checked += 1;
+
+                let result = panic::catch_unwind(|| aoc::solve(day, part, &input));
+                let failure = match result {
+                    Ok(Ok(answer)) if answer == expected.trim() => continue,
+                    Ok(Ok(answer)) => format!("answered {}, expected {}", answer, expected.trim()),
+                    Ok(Err(msg)) => msg,
+                    Err(_) => "panicked".to_string(),
+                };
+                failures.push(format!("{} part {}: {}", sample.display(), part, failure));
+            }
+        }
+    }
+
+    assert!(checked > 0, "no expected answers found");
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
+
+fn sample_files(day: u8) -> Vec<PathBuf> {
+    let mut samples: Vec<PathBuf> = fs::read_dir(aoc::resources_dir(day))
+        .expect("read resources dir")
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| {
+            let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+            name.starts_with("sample") && name.ends_with(".txt")
+        })
+        .collect();
+    samples.sort();
+    samples
+}
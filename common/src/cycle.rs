@@ -0,0 +1,65 @@
+use std::{collections::HashMap, hash::Hash};
+
+// where a sequence of states starts repeating, `start` is the index of the first state inside
+// the loop and `period` is the loop's length
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub period: usize,
+}
+
+impl Cycle {
+    // the index of the first state that's the same as state `n`
+    pub fn equivalent(&self, n: usize) -> usize {
+        match n < self.start {
+            true => n,
+            false => self.start + (n - self.start) % self.period,
+        }
+    }
+}
+
+// steps from `initial` until a state comes up a second time, every state seen is kept by hash
+// so the repeat is caught exactly where it happens; returns the cycle along with the states in
+// order, enough to look up any later state through `Cycle::equivalent`
+pub fn find_cycle<T: Hash + Eq + Clone>(initial: T, step: impl FnMut(&T) -> T) -> (Cycle, Vec<T>) {
+    match find_cycle_within(initial, None, step) {
+        (Some(cycle), states, _) => (cycle, states),
+        (None, _, _) => unreachable!("walks without a limit only stop at a cycle"),
+    }
+}
+
+// the state `n` steps after `initial`, skipping ahead as soon as the states start repeating
+// so huge `n`s cost no more than one trip around the loop
+pub fn nth_state<T: Hash + Eq + Clone>(initial: T, n: usize, step: impl FnMut(&T) -> T) -> T {
+    match find_cycle_within(initial, Some(n), step) {
+        (Some(cycle), mut states, _) => states.swap_remove(cycle.equivalent(n)),
+        (None, _, state) => state,
+    }
+}
+
+// steps until a state repeats or `limit` steps have been taken, whichever is first; returns
+// the cycle if one was found, the states before the last one and the last one
+pub fn find_cycle_within<T: Hash + Eq + Clone>(
+    initial: T,
+    limit: Option<usize>,
+    mut step: impl FnMut(&T) -> T,
+) -> (Option<Cycle>, Vec<T>, T) {
+    let mut seen: HashMap<T, usize> = HashMap::new();
+    let mut states: Vec<T> = vec![];
+    let mut state = initial;
+
+    while limit.is_none_or(|limit| states.len() < limit) {
+        if let Some(start) = seen.get(&state) {
+            let cycle = Cycle {
+                start: *start,
+                period: states.len() - start,
+            };
+            return (Some(cycle), states, state);
+        }
+        let next = step(&state);
+        seen.insert(state.clone(), states.len());
+        states.push(state);
+        state = next;
+    }
+    (None, states, state)
+}
@@ -0,0 +1,52 @@
+use std::{
+    env, fs,
+    io::{self, stdin, IsTerminal, Read},
+    path::{Path, PathBuf},
+    process,
+};
+
+// the day's `resources/input.txt`, resolved from the manifest dir of the crate using it
+#[macro_export]
+macro_rules! default_input_path {
+    () => {
+        ::std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("resources")
+            .join("input.txt")
+    };
+}
+
+// reads the input from `path` if one was given, otherwise from stdin when something is piped
+// in, otherwise from `default_path`
+pub fn load(path: Option<&Path>, default_path: &Path) -> io::Result<String> {
+    if let Some(path) = path {
+        return fs::read_to_string(path);
+    }
+
+    let stdin = stdin();
+    if !stdin.is_terminal() {
+        let mut input = String::new();
+        stdin.lock().read_to_string(&mut input)?;
+        if !input.is_empty() {
+            return Ok(input);
+        }
+    }
+
+    fs::read_to_string(default_path)
+}
+
+// same as `load`, taking the path from the first command line argument
+pub fn load_from_args(default_path: &Path) -> io::Result<String> {
+    let path: Option<PathBuf> = env::args().nth(1).map(PathBuf::from);
+    load(path.as_deref(), default_path)
+}
+
+// the loaded input, or a message and exit code 1 when it couldn't be read
+pub fn or_exit(loaded: io::Result<String>) -> String {
+    match loaded {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("couldn't read input: {}", e);
+            process::exit(1);
+        }
+    }
+}
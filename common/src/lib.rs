@@ -0,0 +1,8 @@
+pub mod cycle;
+pub mod input;
+pub mod parse;
+mod solution;
+pub mod term;
+
+pub use parse::ParseError;
+pub use solution::{print_answers, Solution};
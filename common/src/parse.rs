@@ -0,0 +1,76 @@
+use std::{error::Error, fmt::Display, str::FromStr};
+
+// where and why a day's input couldn't be parsed, lines and columns count from 1
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    line: usize,
+    column: usize,
+    token: String,
+    reason: String,
+}
+
+impl ParseError {
+    pub fn new(
+        line: usize,
+        column: usize,
+        token: impl Into<String>,
+        reason: impl Into<String>,
+    ) -> Self {
+        Self {
+            line,
+            column,
+            token: token.into(),
+            reason: reason.into(),
+        }
+    }
+
+    // `token` has to be a slice of `line` for its column to be found, `line_idx` is the 0-based
+    // index handed out by `enumerate`
+    pub fn in_line(line_idx: usize, line: &str, token: &str, reason: impl Into<String>) -> Self {
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(line.as_ptr() as usize)
+            .filter(|offset| *offset <= line.len())
+            .unwrap_or(0);
+        Self::new(
+            line_idx + 1,
+            line[..offset].chars().count() + 1,
+            token,
+            reason,
+        )
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {} '{}'",
+            self.line, self.column, self.reason, self.token
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+// parses a number out of `token`, a slice of `line`
+pub fn number<T: FromStr>(line_idx: usize, line: &str, token: &str) -> Result<T, ParseError> {
+    token
+        .parse::<T>()
+        .map_err(|_| ParseError::in_line(line_idx, line, token, "expected a number, found"))
+}
@@ -0,0 +1,25 @@
+use crate::ParseError;
+use std::{fmt::Display, process};
+
+// every day parses its input once and answers both parts from the parsed form
+pub trait Solution {
+    type Input;
+    type Output: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Self::Output;
+    fn part2(input: &Self::Input) -> Self::Output;
+}
+
+// a malformed input is reported and exits instead of panicking
+pub fn print_answers<S: Solution>(input: &str) {
+    let parsed = match S::parse(input) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            eprintln!("couldn't parse input: {}", e);
+            process::exit(1);
+        }
+    };
+    println!("part 1: {}", S::part1(&parsed));
+    println!("part 2: {}", S::part2(&parsed));
+}
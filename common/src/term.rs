@@ -0,0 +1,4 @@
+// resets the terminal so the next frame of an animation is drawn over the last one
+pub fn clear() {
+    print!("{esc}c", esc = 27 as char);
+}
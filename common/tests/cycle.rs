@@ -0,0 +1,37 @@
+use common::cycle::{find_cycle, Cycle};
+
+#[test]
+fn finds_where_the_loop_starts_and_its_length() {
+    // 0 1 2 3 4 5 6 then back to 3
+    let (cycle, states) = find_cycle(0, |n| if *n == 6 { 3 } else { n + 1 });
+
+    assert_eq!(
+        cycle,
+        Cycle {
+            start: 3,
+            period: 4
+        }
+    );
+    assert_eq!(states, vec![0, 1, 2, 3, 4, 5, 6]);
+}
+
+#[test]
+fn maps_far_off_states_back_into_the_loop() {
+    let cycle = Cycle {
+        start: 3,
+        period: 4,
+    };
+
+    assert_eq!(cycle.equivalent(2), 2);
+    assert_eq!(cycle.equivalent(7), 3);
+    assert_eq!(cycle.equivalent(1000000000), 3 + (1000000000 - 3) % 4);
+}
+
+#[test]
+fn nth_state_skips_around_the_loop() {
+    let step = |n: &u32| if *n == 6 { 3 } else { n + 1 };
+
+    assert_eq!(common::cycle::nth_state(0, 0, step), 0);
+    assert_eq!(common::cycle::nth_state(0, 5, step), 5);
+    assert_eq!(common::cycle::nth_state(0, 1000000000, step), 4);
+}
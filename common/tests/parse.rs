@@ -0,0 +1,21 @@
+use common::{parse, ParseError};
+
+#[test]
+fn in_line_finds_the_token_column() {
+    let line = "Game 12: 3 blue, 4 purple";
+    let err = ParseError::in_line(4, line, &line[19..], "unknown color");
+
+    assert_eq!((err.line(), err.column(), err.token()), (5, 20, "purple"));
+    assert_eq!(err.to_string(), "line 5, column 20: unknown color 'purple'");
+}
+
+#[test]
+fn number_reports_what_it_couldnt_parse() {
+    let line = "seeds: 79 1x4";
+
+    assert_eq!(parse::number::<i64>(0, line, &line[7..9]), Ok(79));
+    assert_eq!(
+        parse::number::<i64>(0, line, &line[10..]),
+        Err(ParseError::new(1, 11, "1x4", "expected a number, found"))
+    );
+}
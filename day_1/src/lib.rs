@@ -0,0 +1,90 @@
+use common::{ParseError, Solution};
+
+const PATTERNS: [&str; 10] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+const REV_PATTERNS: [&str; 10] = [
+    "orez", "eno", "owt", "eerht", "ruof", "evif", "xis", "neves", "thgie", "enin",
+];
+
+const RADIX: u32 = 10;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<String>;
+    type Output = usize;
+
+    // every line needs at least one digit, numeric or spelled out, to be calibrated
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                if let Some((col, chr)) = line
+                    .char_indices()
+                    .find(|(_, c)| !c.is_ascii_alphanumeric())
+                {
+                    return Err(ParseError::in_line(
+                        i,
+                        line,
+                        &line[col..col + chr.len_utf8()],
+                        "unexpected character",
+                    ));
+                }
+                let has_digit = line.chars().any(|c| c.is_digit(RADIX))
+                    || PATTERNS.iter().any(|pattern| line.contains(pattern));
+                if !has_digit {
+                    return Err(ParseError::in_line(i, line, line, "no digit in line"));
+                }
+                Ok(line.to_string())
+            })
+            .collect()
+    }
+
+    fn part1(input: &Self::Input) -> Self::Output {
+        sum_calibration_values(input, false)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Output {
+        sum_calibration_values(input, true)
+    }
+}
+
+// lines without a digit part 1 can read count for nothing
+fn sum_calibration_values(recs: &[String], spelled: bool) -> usize {
+    recs.iter()
+        .filter_map(|rec| calibration_value(rec, spelled))
+        .sum::<usize>()
+}
+
+// the first and last digit of the line as a two digit number, `spelled` also counts "one" etc.
+pub fn calibration_value(rec: &str, spelled: bool) -> Option<usize> {
+    let first = find_first_digit(rec.to_string(), false, spelled)?;
+    let last = find_first_digit(rec.to_string(), true, spelled)?;
+    Some(first * 10 + last)
+}
+
+fn find_first_digit(mut string: String, rev: bool, spelled: bool) -> Option<usize> {
+    let patterns;
+    if rev {
+        string = string.chars().rev().collect::<String>();
+        patterns = REV_PATTERNS;
+    } else {
+        patterns = PATTERNS;
+    }
+
+    for (i, c) in string.char_indices() {
+        if let Some(digit) = c.to_digit(RADIX) {
+            return Some(digit as usize);
+        }
+
+        // part 1 only counts numeric digits
+        if spelled {
+            if let Some(digit) = patterns.iter().position(|p| string[i..].starts_with(p)) {
+                return Some(digit);
+            }
+        }
+    }
+    None
+}
@@ -0,0 +1,42 @@
+use common::{ParseError, Solution};
+use grid::Grid;
+use map::{AreaMethod, Map};
+pub mod map;
+pub mod render;
+pub mod validate;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Map;
+    type Output = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let tiles: Grid<char> = Grid::parse(input, Some)?;
+        let mut map: Map = Map::new(tiles.width(), tiles.height());
+
+        for (pos, chr) in tiles.iter() {
+            map.add_pos_from_char(pos, *chr)?;
+        }
+
+        // the start tile's pipe is worked out up front so a map without a single loop
+        // through it is rejected with the rest of the bad input
+        if let Err(e) = map.infer_start() {
+            let (line, column) = match tiles.position(|c| *c == 'S') {
+                Some(start) => (start.y + 1, start.x + 1),
+                None => (1, 1),
+            };
+            return Err(ParseError::new(line, column, "S", e.to_string()));
+        }
+
+        Ok(map)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Output {
+        input.clone().find_dist_to_farthest_pos()
+    }
+
+    fn part2(input: &Self::Input) -> Self::Output {
+        input.clone().enclosed_area(AreaMethod::Scanline)
+    }
+}
@@ -1,18 +1,191 @@
-use std::io::{stdin, BufRead, BufReader};
+use common::{input, Solution};
+use day_10::{
+    map::AreaMethod,
+    render::{self, CastWriter, Observer, PpmWriter, TerminalRenderer},
+    validate, Day10,
+};
+use std::{cell::RefCell, env, fs, path::Path, process, rc::Rc, time::Duration};
 
-use map::{Map, Tup2D};
-mod map;
+const USAGE: &str = "usage: day_10 [<input>] [--visualize] [--delay <ms>] [--frames <dir>]
+              [--cast <path>]
+       day_10 [<input>] --check [--clean]
+       day_10 [<input>] --draw [--out <path>] [--no-color]";
+
+// PPM frames are this many pixels a tile
+const FRAME_SCALE: usize = 4;
+
+// `--visualize` draws the flood fill in the terminal while it finds part 2's answer, `--frames`
+// saves every step of it as an image and `--cast` as an asciinema recording, `--delay` apart;
+// `--check` lists what's wrong with the maze instead and `--clean` prints it with only the loop,
+// `--draw` prints it in box-drawing pipes or writes it to `--out`
+struct Args {
+    input: Option<String>,
+    visualize: bool,
+    delay: Duration,
+    frames: Option<String>,
+    cast: Option<String>,
+    check: bool,
+    clean: bool,
+    draw: bool,
+    out: Option<String>,
+    color: bool,
+}
 
 fn main() {
-    let lines = BufReader::new(stdin().lock()).lines();
-    let data = lines.filter_map(Result::ok).collect::<Vec<String>>();
-    let mut map: Map = Map::new(Tup2D::new(data[0].len() as i32, data.len() as i32));
+    let args: Vec<String> = env::args().skip(1).collect();
+    let args = match parse_args(&args) {
+        Ok(args) => args,
+        Err(msg) => {
+            eprintln!("{}\n{}", msg, USAGE);
+            process::exit(2);
+        }
+    };
+
+    let input = input::or_exit(input::load(
+        args.input.as_deref().map(Path::new),
+        &common::default_input_path!(),
+    ));
+
+    if args.check || args.clean {
+        check(&input, args.check, args.clean);
+        return;
+    }
+
+    if args.draw {
+        draw(&input, args.out.as_deref(), args.color);
+        return;
+    }
+
+    let mut observers: Vec<Box<dyn Observer>> = vec![];
+    if args.visualize {
+        observers.push(Box::new(TerminalRenderer::new(args.delay)));
+    }
+    if let Some(dir) = &args.frames {
+        match PpmWriter::new(Path::new(dir), FRAME_SCALE) {
+            Ok(writer) => observers.push(Box::new(writer)),
+            Err(e) => exit_with(&format!("couldn't create {}: {}", dir, e)),
+        }
+    }
+    if let Some(path) = &args.cast {
+        match CastWriter::new(Path::new(path), args.delay) {
+            Ok(writer) => observers.push(Box::new(writer)),
+            Err(e) => exit_with(&format!("couldn't create {}: {}", path, e)),
+        }
+    }
+
+    if observers.is_empty() {
+        common::print_answers::<Day10>(&input);
+        return;
+    }
+
+    let mut map = match Day10::parse(&input) {
+        Ok(map) => map,
+        Err(e) => exit_with(&format!("couldn't parse input: {}", e)),
+    };
+    let observers = Rc::new(RefCell::new(observers));
+    map.set_observer(observers.clone());
+    let enclosed = map.enclosed_area(AreaMethod::FloodFill);
+    if let Err(e) = observers.borrow_mut().finish() {
+        exit_with(&format!("couldn't save the animation: {}", e));
+    }
+    println!("part 1: {}", Day10::part1(&map));
+    println!("part 2: {}", enclosed);
+}
+
+fn check(input: &str, list_issues: bool, clean: bool) {
+    let report = match validate::validate(input) {
+        Ok(report) => report,
+        Err(e) => exit_with(&format!("couldn't parse input: {}", e)),
+    };
+    if list_issues {
+        for issue in report.issues() {
+            println!("{}", issue);
+        }
+        if report.is_valid() {
+            println!("no issues found");
+        }
+    }
+    if clean {
+        println!("{}", report.cleaned());
+    }
+}
+
+fn draw(input: &str, out: Option<&str>, color: bool) {
+    let mut map = match Day10::parse(input) {
+        Ok(map) => map,
+        Err(e) => exit_with(&format!("couldn't parse input: {}", e)),
+    };
+    let drawing = render::box_drawing(&mut map, color);
+    match out {
+        Some(path) => {
+            if let Err(e) = fs::write(path, drawing + "\n") {
+                exit_with(&format!("couldn't write {}: {}", path, e));
+            }
+        }
+        None => println!("{}", drawing),
+    }
+}
+
+fn exit_with(msg: &str) -> ! {
+    eprintln!("{}", msg);
+    process::exit(1);
+}
+
+fn parse_args(args: &[String]) -> Result<Args, String> {
+    let mut parsed = Args {
+        input: None,
+        visualize: false,
+        delay: render::DEFAULT_DELAY,
+        frames: None,
+        cast: None,
+        check: false,
+        clean: false,
+        draw: false,
+        out: None,
+        color: true,
+    };
 
-    for (y, line) in data.iter().enumerate() {
-        for (x, chr) in line.char_indices() {
-            map.add_pos_from_char(Tup2D::new(x as i32, y as i32), chr);
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--visualize" => parsed.visualize = true,
+            "--delay" => {
+                parsed.delay = match iter.next() {
+                    Some(ms) => match ms.parse() {
+                        Ok(ms) => Duration::from_millis(ms),
+                        Err(_) => return Err(format!("invalid delay '{}'", ms)),
+                    },
+                    None => return Err("missing value for --delay".to_string()),
+                }
+            }
+            "--check" => parsed.check = true,
+            "--clean" => parsed.clean = true,
+            "--draw" => parsed.draw = true,
+            "--no-color" => parsed.color = false,
+            "--out" => {
+                parsed.out = match iter.next() {
+                    Some(path) => Some(path.to_string()),
+                    None => return Err("missing value for --out".to_string()),
+                }
+            }
+            "--frames" => {
+                parsed.frames = match iter.next() {
+                    Some(dir) => Some(dir.to_string()),
+                    None => return Err("missing value for --frames".to_string()),
+                }
+            }
+            "--cast" => {
+                parsed.cast = match iter.next() {
+                    Some(path) => Some(path.to_string()),
+                    None => return Err("missing value for --cast".to_string()),
+                }
+            }
+            path if parsed.input.is_none() && !path.starts_with("--") => {
+                parsed.input = Some(path.to_string())
+            }
+            other => return Err(format!("unknown argument '{}'", other)),
         }
     }
 
-    println!("enclosed tiles: {}", map.find_enclosed_positions());
+    Ok(parsed)
 }
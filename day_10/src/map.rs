@@ -1,75 +1,26 @@
+use crate::render::{NoOp, Observer};
+use common::ParseError;
+use grid::{Grid, Point};
 use itertools::Itertools;
 use std::{
-    collections::{HashMap, HashSet},
+    cell::RefCell,
+    collections::{hash_map::Entry, HashMap, HashSet, VecDeque},
+    error::Error,
     fmt::{Debug, Display},
-    time::Duration,
+    rc::Rc,
 };
 
-const DISPLAY_DELAY: Duration = Duration::from_secs(2);
-
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
-pub struct Tup2D {
-    x: i32,
-    y: i32,
-}
-
-impl Tup2D {
-    pub fn new(x: i32, y: i32) -> Self {
-        Self { x, y }
-    }
-
-    pub fn new_doubled(&self) -> Self {
-        Tup2D::new(self.x * 2, self.y * 2)
-    }
-
-    pub fn new_halved(&self) -> Self {
-        Tup2D::new(self.x / 2, self.y / 2)
-    }
-
-    pub fn new_avg(&self, other: &Tup2D) -> Self {
-        let new_y = match self.y == other.y {
-            true => self.y,
-            false => self.y.max(other.y) - 1,
-        };
-        let new_x = match self.x == other.x {
-            true => self.x,
-            false => self.x.max(other.x) - 1,
-        };
-        return Tup2D::new(new_x, new_y);
-    }
-
-    pub fn get_adj(&self) -> [Tup2D; 4] {
-        [
-            Tup2D::new(self.x - 1, self.y),
-            Tup2D::new(self.x + 1, self.y),
-            Tup2D::new(self.x, self.y + 1),
-            Tup2D::new(self.x, self.y - 1),
-        ]
-    }
-}
-
-impl PartialOrd for Tup2D {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        match self.y.partial_cmp(&other.y) {
-            Some(core::cmp::Ordering::Equal) => {}
-            ord => return ord,
-        }
-        self.x.partial_cmp(&other.x)
-    }
+fn doubled(p: Point) -> Point {
+    Point::new(p.x * 2, p.y * 2)
 }
 
-impl Ord for Tup2D {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        match self.y.cmp(&other.y) {
-            std::cmp::Ordering::Equal => {}
-            ord => return ord,
-        }
-        self.x.cmp(&other.x)
-    }
+// the point halfway between two points of the doubled map
+fn midpoint(p1: Point, p2: Point) -> Point {
+    Point::new((p1.x + p2.x) / 2, (p1.y + p2.y) / 2)
 }
 
 #[derive(Clone, Debug, Copy, Eq, PartialEq)]
-enum TileType {
+pub enum TileType {
     Loop,
     Inside,
     Outside,
@@ -91,131 +42,332 @@ impl Display for TileType {
     }
 }
 
+// ways of counting the tiles the loop encloses, they all give the same count
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AreaMethod {
+    // flood fill a map doubled in size so gaps between pipes can be squeezed through, the
+    // map's observer sees every step
+    FloodFill,
+    // scan each row, the tiles past an odd number of loop pipes going north are inside
+    Scanline,
+    // the loop's area from the shoelace formula, then its inner tiles from Pick's theorem
+    Shoelace,
+}
+
+// why the pipe under the start tile couldn't be worked out, reads as a reason for the `S`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StartError {
+    Missing,
+    // no two of the pipes around it lead back to each other
+    Broken,
+    // every pipe shape that would close a loop
+    Ambiguous(Vec<char>),
+}
+
+impl Display for StartError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StartError::Missing => write!(f, "no start tile"),
+            StartError::Broken => write!(f, "no loop runs through"),
+            StartError::Ambiguous(glyphs) => write!(
+                f,
+                "more than one loop ({}) runs through",
+                glyphs.iter().join(", ")
+            ),
+        }
+    }
+}
+
+impl Error for StartError {}
+
+// (dx, dy) to the tiles a pipe connects, none for the ground and no pipe for anything else
+pub fn pipe_ends(chr: char) -> Option<&'static [(isize, isize)]> {
+    match chr {
+        '|' => Some(&[(0, -1), (0, 1)]),
+        '-' => Some(&[(-1, 0), (1, 0)]),
+        'L' => Some(&[(0, -1), (1, 0)]),
+        'J' => Some(&[(0, -1), (-1, 0)]),
+        '7' => Some(&[(0, 1), (-1, 0)]),
+        'F' => Some(&[(0, 1), (1, 0)]),
+        '.' => Some(&[]),
+        _ => None,
+    }
+}
+
+// the pipe joining the tiles `(dx, dy)` away, in either order
+pub(crate) fn glyph(a: (isize, isize), b: (isize, isize)) -> char {
+    match (a.min(b), a.max(b)) {
+        ((0, -1), (0, 1)) => '|',
+        ((-1, 0), (1, 0)) => '-',
+        ((0, -1), (1, 0)) => 'L',
+        ((-1, 0), (0, -1)) => 'J',
+        ((-1, 0), (0, 1)) => '7',
+        ((0, 1), (1, 0)) => 'F',
+        _ => unreachable!("no pipe joins {:?} and {:?}", a, b),
+    }
+}
+
+// pipes linked to each other, a loop if every one of them is linked to exactly two others
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Component {
+    pub tiles: Vec<Point>,
+    pub is_loop: bool,
+}
+
+#[derive(Clone)]
 pub struct Map {
-    start: Option<Tup2D>,
-    farthest_point: Option<Tup2D>,
-    size: Tup2D,
-    conns: HashMap<Tup2D, Vec<Tup2D>>,
-    loop_points: HashSet<Tup2D>,
-    start_conns_built: bool,
+    start: Option<Point>,
+    farthest_point: Option<Point>,
+    conns: Grid<Vec<Point>>,
+    loop_points: HashSet<Point>,
+    // the pipe under the start tile, once its connections are worked out
+    start_glyph: Option<char>,
+    // shared by clones, so a map can be cloned to solve it and still be watched
+    observer: Rc<RefCell<dyn Observer>>,
 }
 
 impl Map {
-    pub fn new(size: Tup2D) -> Self {
+    pub fn new(width: usize, height: usize) -> Self {
         Self {
             start: None,
             farthest_point: None,
-            size: size,
-            conns: HashMap::new(),
+            conns: Grid::new(width, height, vec![]),
             loop_points: HashSet::new(),
-            start_conns_built: false,
+            start_glyph: None,
+            observer: Rc::new(RefCell::new(NoOp)),
         }
     }
 
-    pub fn add_pos_from_char(&mut self, pos: Tup2D, chr: char) {
-        assert!(self.check_in_bounds(pos));
-        let conn: Vec<Tup2D> = match chr {
-            '|' => vec![Tup2D::new(pos.x, pos.y - 1), Tup2D::new(pos.x, pos.y + 1)],
-            '-' => vec![Tup2D::new(pos.x - 1, pos.y), Tup2D::new(pos.x + 1, pos.y)],
-            'L' => vec![Tup2D::new(pos.x, pos.y - 1), Tup2D::new(pos.x + 1, pos.y)],
-            'J' => vec![Tup2D::new(pos.x, pos.y - 1), Tup2D::new(pos.x - 1, pos.y)],
-            '7' => vec![Tup2D::new(pos.x, pos.y + 1), Tup2D::new(pos.x - 1, pos.y)],
-            'F' => vec![Tup2D::new(pos.x, pos.y + 1), Tup2D::new(pos.x + 1, pos.y)],
-            '.' => vec![],
+    // the observer sees every step of the flood fill, see `AreaMethod::FloodFill`
+    pub fn set_observer(&mut self, observer: Rc<RefCell<dyn Observer>>) {
+        self.observer = observer;
+    }
+
+    fn show(&self, doubled_map: &Grid<TileType>) {
+        self.observer.borrow_mut().frame(&half_map(doubled_map));
+    }
+
+    pub fn add_pos_from_char(&mut self, pos: Point, chr: char) -> Result<(), ParseError> {
+        let err = |reason: &str| ParseError::new(pos.y + 1, pos.x + 1, chr, reason);
+        if !self.conns.contains(pos) {
+            return Err(err("tile outside the map"));
+        }
+        let deltas = match chr {
             'S' => {
+                if self.start.is_some() {
+                    return Err(err("second start tile"));
+                }
                 self.start = Some(pos);
-                return;
+                return Ok(());
             } // can't be sure of start conns here
-            _ => panic!("unexpected char '{}'", chr),
+            _ => pipe_ends(chr).ok_or_else(|| err("unexpected tile"))?,
         };
-        self.conns.insert(
-            pos,
-            conn.iter()
-                .filter(|c| self.check_in_bounds(**c))
-                .map(|c| *c)
-                .collect(),
-        );
+        self.conns[pos] = deltas
+            .iter()
+            .filter_map(|(dx, dy)| pos.offset(*dx, *dy))
+            .filter(|c| self.conns.contains(*c))
+            .collect();
+        Ok(())
+    }
+
+    pub fn has_start(&self) -> bool {
+        self.start.is_some()
+    }
+
+    pub fn start(&self) -> Option<Point> {
+        self.start
     }
 
-    fn check_in_bounds(&self, pos: Tup2D) -> bool {
-        pos.x >= 0 && pos.y >= 0 && pos.x < self.size.x && pos.y < self.size.y
+    // the tiles the pipe at `pos` leads to, leaving out any off the map
+    pub fn connections(&self, pos: Point) -> &[Point] {
+        &self.conns[pos]
     }
 
-    fn get_connections(&self, pos: Tup2D) -> Option<Vec<Tup2D>> {
-        self.conns.get(&pos).cloned()
+    // the tiles of the loop through the start tile
+    pub fn loop_tiles(&mut self) -> &HashSet<Point> {
+        if self.loop_points.is_empty() {
+            self.find_dist_to_farthest_pos();
+        }
+        &self.loop_points
+    }
+
+    // the tiles the pipe at `pos` leads to that lead back to it
+    pub fn links(&self, pos: Point) -> Vec<Point> {
+        self.conns[pos]
+            .iter()
+            .filter(|p| self.conns[**p].contains(&pos))
+            .copied()
+            .collect()
+    }
+
+    // BFS along the pipes from every origin at once, each tile reached is as far as it is from
+    // the nearest origin
+    pub fn distances_from(&self, origins: &[Point]) -> HashMap<Point, usize> {
+        let mut distances: HashMap<Point, usize> = HashMap::new();
+        let mut to_visit: VecDeque<Point> = VecDeque::new();
+        for origin in origins.iter().filter(|p| self.conns.contains(**p)) {
+            if distances.insert(*origin, 0).is_none() {
+                to_visit.push_back(*origin);
+            }
+        }
+
+        while let Some(pos) = to_visit.pop_front() {
+            let dist = distances[&pos];
+            for next in self.links(pos) {
+                if let Entry::Vacant(e) = distances.entry(next) {
+                    e.insert(dist + 1);
+                    to_visit.push_back(next);
+                }
+            }
+        }
+        distances
+    }
+
+    // steps along the pipes between two tiles, if they're connected at all
+    pub fn distance(&self, from: Point, to: Point) -> Option<usize> {
+        self.distances_from(&[from]).get(&to).copied()
+    }
+
+    // the tile the most steps along the pipes from the nearest origin, ties go to the first
+    // one in reading order
+    pub fn farthest_from(&self, origins: &[Point]) -> Option<(Point, usize)> {
+        farthest(&self.distances_from(origins))
+    }
+
+    // every group of pipes linked to each other, in reading order of their first tiles
+    pub fn components(&self) -> Vec<Component> {
+        let mut seen: HashSet<Point> = HashSet::new();
+        let mut components: Vec<Component> = vec![];
+        for (pos, conns) in self.conns.iter() {
+            if conns.is_empty() || seen.contains(&pos) {
+                continue;
+            }
+            let mut tiles: Vec<Point> = self.distances_from(&[pos]).into_keys().collect();
+            tiles.sort();
+            seen.extend(tiles.iter().copied());
+            let is_loop = tiles.len() > 2 && tiles.iter().all(|p| self.links(*p).len() == 2);
+            components.push(Component { tiles, is_loop });
+        }
+        components
+    }
+
+    // the tile farthest from the start along the loop, once the loop has been walked
+    pub fn farthest_point(&self) -> Option<Point> {
+        self.farthest_point
     }
 
     // BFS style traversal staying in pipe
     pub fn find_dist_to_farthest_pos(&mut self) -> i32 {
-        if !self.start_conns_built {
-            self.build_start_conns();
+        if self.start_glyph.is_none() {
+            self.infer_start()
+                .expect("a single loop through the start tile");
         }
-        let mut to_visit: HashMap<Tup2D, i32> = HashMap::new();
-        let mut visited: HashSet<Tup2D> = HashSet::new();
-
-        to_visit.insert(self.start.unwrap(), 0i32);
-
-        let mut farthest_point: (Tup2D, i32) = (self.start.unwrap(), 0);
-
-        while !to_visit.is_empty() {
-            let mut potential_to_visit: Vec<(Tup2D, i32)> = vec![];
-            for (pos, dist) in to_visit.iter_mut() {
-                potential_to_visit.extend(
-                    self.get_connections(*pos)
-                        .unwrap()
-                        .iter()
-                        .map(|p| (*p, *dist + 1)),
-                );
-                visited.insert(*pos);
-            }
+        let start = self.start.expect("start pos set");
+        let distances = self.distances_from(&[start]);
+        let (farthest_point, dist) = farthest(&distances).expect("start on the map");
 
-            to_visit.clear();
+        self.farthest_point = Some(farthest_point);
+        self.loop_points = distances.into_keys().collect();
+        return dist as i32;
+    }
+
+    pub fn enclosed_area(&mut self, method: AreaMethod) -> i32 {
+        match method {
+            AreaMethod::FloodFill => self.find_enclosed_positions(),
+            AreaMethod::Scanline => self.scan_enclosed_positions(),
+            AreaMethod::Shoelace => self.shoelace_enclosed_positions(),
+        }
+    }
 
-            potential_to_visit
-                .iter_mut()
-                .filter(|(p, _)| !visited.contains(p))
-                .for_each(|(p, d)| {
-                    if *d > farthest_point.1 {
-                        farthest_point = (*p, *d);
+    fn scan_enclosed_positions(&mut self) -> i32 {
+        return self
+            .regions()
+            .iter()
+            .filter(|(_, t)| **t == TileType::Inside)
+            .count() as i32;
+    }
+
+    // every tile as part of the loop, inside or outside it; a tile is inside when crossing to
+    // the edge of its row means crossing the loop an odd number of times, and a pipe counts as
+    // a crossing if it goes north, so runs like `L-7` count once and `L-J` don't at all
+    pub fn regions(&mut self) -> Grid<TileType> {
+        if self.loop_points.is_empty() {
+            self.find_dist_to_farthest_pos();
+        }
+        let mut regions = Grid::new(self.conns.width(), self.conns.height(), TileType::Outside);
+        for y in 0..self.conns.height() {
+            let mut inside = false;
+            for x in 0..self.conns.width() {
+                let pos = Point::new(x, y);
+                if !self.loop_points.contains(&pos) {
+                    if inside {
+                        regions[pos] = TileType::Inside;
                     }
-                    to_visit.insert(*p, *d);
-                });
+                } else {
+                    regions[pos] = TileType::Loop;
+                    if self.conns[pos].iter().any(|c| c.y < y) {
+                        inside = !inside;
+                    }
+                }
+            }
         }
-        self.farthest_point = Some(farthest_point.0);
-        println!("loop size: {}", visited.len());
-        self.loop_points = visited;
-        return farthest_point.1;
+        regions
+    }
+
+    // twice the area of the polygon through the loop's tiles is the sum of the cross products
+    // of its corners, and Pick's theorem gives the tiles inside it: A = inside + loop / 2 - 1
+    fn shoelace_enclosed_positions(&mut self) -> i32 {
+        if self.start_glyph.is_none() {
+            self.infer_start()
+                .expect("a single loop through the start tile");
+        }
+        let start = self.start.expect("start pos set");
+        let mut prev = start;
+        let mut pos = match self.conns[start].first() {
+            Some(next) => *next,
+            None => return 0,
+        };
+        let mut doubled_area: i64 = (start.x * pos.y) as i64 - (pos.x * start.y) as i64;
+        let mut loop_len: i64 = 1;
+
+        while pos != start {
+            let next = *self.conns[pos]
+                .iter()
+                .find(|c| **c != prev)
+                .expect("loop continues past every pipe");
+            doubled_area += (pos.x * next.y) as i64 - (next.x * pos.y) as i64;
+            loop_len += 1;
+            (prev, pos) = (pos, next);
+        }
+
+        return ((doubled_area.abs() - loop_len) / 2 + 1) as i32;
     }
 
     pub fn find_enclosed_positions(&mut self) -> i32 {
         if self.loop_points.is_empty() {
             self.find_dist_to_farthest_pos();
         }
-        let doubled_size: Tup2D = self.size.new_doubled();
-        let mut doubled_map: HashMap<Tup2D, TileType> = HashMap::new();
-
         // clean empty map
-        (0..doubled_size.x)
-            .cartesian_product(0..doubled_size.y)
-            .for_each(|(x, y)| {
-                doubled_map.insert(Tup2D::new(x, y), TileType::Unknown);
-            });
+        let mut doubled_map: Grid<TileType> = Grid::new(
+            self.conns.width() * 2,
+            self.conns.height() * 2,
+            TileType::Unknown,
+        );
 
-        print_map_with_delay(&half_map(&doubled_map), std::time::Duration::from_secs(0));
+        self.show(&doubled_map);
 
         // double map size and interpolate points between connections
-        let mut interpol_loop_points: Vec<Tup2D> = self
+        let mut interpol_loop_points: Vec<Point> = self
             .conns
             .iter()
-            .filter(|(pos, _)| self.loop_points.contains(*pos))
+            .filter(|(pos, _)| self.loop_points.contains(pos))
             .flat_map(|(pos, conns)| {
-                return conns.iter().map(|c| (*c, *pos));
+                return conns.iter().map(move |c| (*c, pos));
             })
             .flat_map(|(p1, p2)| {
-                let new_p1 = p1.new_doubled();
-                let new_p2 = p2.new_doubled();
-                let v = vec![new_p1, new_p1.new_avg(&new_p2), new_p2];
-                v
+                let new_p1 = doubled(p1);
+                let new_p2 = doubled(p2);
+                vec![new_p1, midpoint(new_p1, new_p2), new_p2]
             })
             .collect();
         interpol_loop_points.sort();
@@ -223,63 +375,58 @@ impl Map {
 
         // set all remaining tiles to have type 'Loop'
         interpol_loop_points.iter().for_each(|p| {
-            doubled_map.insert(*p, TileType::Loop);
+            doubled_map[*p] = TileType::Loop;
         });
 
-        print_map_with_delay(&half_map(&doubled_map), DISPLAY_DELAY);
-
-        loop {
-            match doubled_map.iter().find(|(_, t)| **t == TileType::Unknown) {
-                Some((pos, _)) => {
-                    let mut outside_known: bool = false;
-                    let mut to_visit: Vec<Tup2D> = vec![*pos];
-                    let mut visited: Vec<Tup2D> = vec![];
-
-                    while !to_visit.is_empty() {
-                        to_visit.iter().for_each(|p| visited.push(*p));
-                        let mut new_to_visit: Vec<Tup2D> = vec![];
-                        for tile in to_visit.iter() {
-                            new_to_visit.extend(
-                                tile.get_adj()
-                                    .iter()
-                                    .filter(|p| !visited.contains(p) && !new_to_visit.contains(p))
-                                    .filter(|p| match doubled_map.get(&p) {
-                                        Some(TileType::Outside) => {
-                                            outside_known = true;
-                                            return false;
-                                        }
-                                        Some(TileType::Loop) => false,
-                                        Some(TileType::Inside) => panic!("how is this possible"),
-                                        Some(TileType::Unknown) => true,
-                                        None => {
-                                            // out of bounds, means it must be outside the loop
-                                            outside_known = true;
-                                            return false;
-                                        }
-                                    })
-                                    .collect::<Vec<_>>(),
-                            );
-                        }
-                        new_to_visit.sort();
-                        new_to_visit.dedup();
-
-                        to_visit.clear();
-                        to_visit.extend(new_to_visit);
+        self.show(&doubled_map);
+
+        while let Some(pos) = doubled_map.position(|t| *t == TileType::Unknown) {
+            let mut outside_known: bool = false;
+            let mut to_visit: Vec<Point> = vec![pos];
+            let mut visited: Vec<Point> = vec![];
+
+            while !to_visit.is_empty() {
+                to_visit.iter().for_each(|p| visited.push(*p));
+                let mut new_to_visit: Vec<Point> = vec![];
+                for tile in to_visit.iter() {
+                    // tiles on the edge of the map must be outside the loop
+                    if tile.x == 0
+                        || tile.y == 0
+                        || tile.x == doubled_map.width() - 1
+                        || tile.y == doubled_map.height() - 1
+                    {
+                        outside_known = true;
                     }
-
-                    visited.iter().for_each(|p| {
-                        doubled_map.insert(
-                            *p,
-                            match outside_known {
-                                true => TileType::Outside,
-                                false => TileType::Inside,
-                            },
-                        );
-                    });
-                    print_map_with_delay(&half_map(&doubled_map), DISPLAY_DELAY);
+                    new_to_visit.extend(
+                        doubled_map
+                            .neighbors4(*tile)
+                            .filter(|p| !visited.contains(p) && !new_to_visit.contains(p))
+                            .filter(|p| match doubled_map[*p] {
+                                TileType::Outside => {
+                                    outside_known = true;
+                                    return false;
+                                }
+                                TileType::Loop => false,
+                                TileType::Inside => panic!("how is this possible"),
+                                TileType::Unknown => true,
+                            })
+                            .collect::<Vec<_>>(),
+                    );
                 }
-                None => break,
+                new_to_visit.sort();
+                new_to_visit.dedup();
+
+                to_visit.clear();
+                to_visit.extend(new_to_visit);
             }
+
+            visited.iter().for_each(|p| {
+                doubled_map[*p] = match outside_known {
+                    true => TileType::Outside,
+                    false => TileType::Inside,
+                };
+            });
+            self.show(&doubled_map);
         }
 
         return half_map(&doubled_map)
@@ -288,63 +435,83 @@ impl Map {
             .count() as i32;
     }
 
-    fn build_start_conns(&mut self) {
-        if let Some(s) = self.start {
-            let neighbors: Vec<Tup2D> = vec![
-                Tup2D::new(s.x - 1, s.y + 1),
-                Tup2D::new(s.x - 1, s.y),
-                Tup2D::new(s.x - 1, s.y - 1),
-                Tup2D::new(s.x, s.y + 1),
-                Tup2D::new(s.x, s.y - 1),
-                Tup2D::new(s.x + 1, s.y + 1),
-                Tup2D::new(s.x + 1, s.y),
-                Tup2D::new(s.x + 1, s.y - 1),
-            ];
-            self.conns.insert(
-                s,
-                neighbors
-                    .iter()
-                    .filter(|p| self.check_in_bounds(**p))
-                    .filter(|p| self.conns.get(*p).expect("neighbor pos").contains(&s))
-                    .map(|p| *p)
-                    .collect(),
-            );
-        } else {
-            panic!("tried to build start connections without start pos set!");
+    // the start tile has the pipe that closes a loop with two of its four neighbors, which
+    // have to lead back to each other without leaving the pipes
+    pub fn infer_start(&mut self) -> Result<char, StartError> {
+        let start = self.start.ok_or(StartError::Missing)?;
+        let neighbors: Vec<Point> = self
+            .conns
+            .neighbors4(start)
+            .filter(|p| self.conns[*p].contains(&start))
+            .collect();
+
+        let mut loops: Vec<(Point, Point)> = vec![];
+        for first in neighbors.iter() {
+            if let Some(last) = self.follow_back(start, *first) {
+                if last != *first && !loops.contains(&(last, *first)) {
+                    loops.push((*first, last));
+                }
+            }
+        }
+
+        let delta = |p: Point| {
+            (
+                p.x as isize - start.x as isize,
+                p.y as isize - start.y as isize,
+            )
+        };
+        let glyphs: Vec<char> = loops
+            .iter()
+            .map(|(a, b)| glyph(delta(*a), delta(*b)))
+            .collect();
+        match loops[..] {
+            [] => Err(StartError::Broken),
+            [(a, b)] => {
+                self.conns[start] = vec![a, b];
+                self.start_glyph = Some(glyphs[0]);
+                Ok(glyphs[0])
+            }
+            _ => Err(StartError::Ambiguous(glyphs)),
         }
     }
-}
 
-fn half_map<T: Copy>(map: &HashMap<Tup2D, T>) -> HashMap<Tup2D, T> {
-    let mut new_map: HashMap<Tup2D, T> = HashMap::new();
-    map.iter().for_each(|(p, t)| {
-        if (p.x % 2 == 0) && (p.y % 2 == 0) {
-            new_map.insert(p.new_halved(), *t);
+    // the pipe under the start tile, if it's been worked out
+    pub fn start_glyph(&self) -> Option<char> {
+        self.start_glyph
+    }
+
+    // follows the pipes from `start` through `first` and returns the last one before getting
+    // back to `start`, if the pipes get back there at all
+    fn follow_back(&self, start: Point, first: Point) -> Option<Point> {
+        let (mut prev, mut pos) = (start, first);
+        for _ in 0..self.conns.width() * self.conns.height() {
+            let next = match self.conns[pos][..] {
+                [a, b] if a == prev => b,
+                [a, b] if b == prev => a,
+                _ => return None,
+            };
+            if next == start {
+                return Some(pos);
+            }
+            (prev, pos) = (pos, next);
         }
-    });
-    new_map
+        None
+    }
 }
 
-fn print_map_with_delay<T: Display + Copy>(map: &HashMap<Tup2D, T>, delay: Duration) {
-    let mut size_x: i32 = 0;
-    map.keys().for_each(|p| {
-        size_x = size_x.max(p.x);
-    });
-    size_x += 1;
-
-    std::thread::sleep(delay);
-    clear_term();
-    println!(
-        "{}\n",
-        map.iter()
-            .sorted_by_key(|(p, _)| *p)
-            .chunks(size_x as usize)
-            .into_iter()
-            .map(|chunk| chunk.into_iter().map(|(_, t)| *t).join(" "))
-            .join("\n")
-    );
+fn farthest(distances: &HashMap<Point, usize>) -> Option<(Point, usize)> {
+    distances
+        .iter()
+        .map(|(p, d)| (*p, *d))
+        .max_by(|(p1, d1), (p2, d2)| d1.cmp(d2).then(p2.cmp(p1)))
 }
 
-fn clear_term() {
-    print!("{esc}c", esc = 27 as char);
+// every other row and column of the doubled map, the tiles of the original map
+fn half_map<T: Copy>(map: &Grid<T>) -> Grid<T> {
+    let cells = map
+        .iter()
+        .filter(|(p, _)| (p.x % 2 == 0) && (p.y % 2 == 0))
+        .map(|(_, t)| *t)
+        .collect();
+    Grid::from_vec(map.width().div_ceil(2), cells)
 }
@@ -0,0 +1,268 @@
+use crate::map::{glyph, Map, TileType};
+use grid::{Grid, Point};
+use itertools::Itertools;
+use std::{
+    fs::{self, File},
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+    thread,
+    time::Duration,
+};
+
+pub const DEFAULT_DELAY: Duration = Duration::from_secs(2);
+
+// handed every step of the flood fill as a frame of the map's tiles, does nothing by default
+pub trait Observer {
+    fn frame(&mut self, _tiles: &Grid<TileType>) {}
+
+    // called once the fill is done, with the first thing that went wrong along the way
+    fn finish(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+pub struct NoOp;
+
+impl Observer for NoOp {}
+
+// every observer sees every frame, in order
+impl Observer for Vec<Box<dyn Observer>> {
+    fn frame(&mut self, tiles: &Grid<TileType>) {
+        for observer in self.iter_mut() {
+            observer.frame(tiles);
+        }
+    }
+
+    // every observer is finished, even after one of them fails
+    fn finish(&mut self) -> io::Result<()> {
+        let mut result = Ok(());
+        for observer in self.iter_mut() {
+            let finished = observer.finish();
+            if result.is_ok() {
+                result = finished;
+            }
+        }
+        result
+    }
+}
+
+// the frame as the terminal shows it, rows of space separated tiles
+fn text(tiles: &Grid<TileType>) -> String {
+    tiles.rows().map(|row| row.iter().join(" ")).join("\n")
+}
+
+// clears the terminal and draws each frame, waiting `delay` between frames
+pub struct TerminalRenderer {
+    delay: Duration,
+    frames: usize,
+}
+
+impl TerminalRenderer {
+    pub fn new(delay: Duration) -> Self {
+        Self { delay, frames: 0 }
+    }
+}
+
+impl Observer for TerminalRenderer {
+    fn frame(&mut self, tiles: &Grid<TileType>) {
+        if self.frames > 0 {
+            thread::sleep(self.delay);
+        }
+        self.frames += 1;
+        common::term::clear();
+        println!("{}\n", text(tiles));
+    }
+}
+
+// writes each frame to `dir` as a binary PPM image, `frame_0000.ppm` onwards, every tile
+// drawn as a `scale` pixels wide square; once a write fails the rest of the frames are dropped
+// and `finish` reports why
+pub struct PpmWriter {
+    dir: PathBuf,
+    scale: usize,
+    frames: usize,
+    error: Option<io::Error>,
+}
+
+impl PpmWriter {
+    pub fn new(dir: &Path, scale: usize) -> io::Result<Self> {
+        fs::create_dir_all(dir)?;
+        Ok(Self {
+            dir: dir.to_path_buf(),
+            scale: scale.max(1),
+            frames: 0,
+            error: None,
+        })
+    }
+
+    fn write(&self, tiles: &Grid<TileType>) -> io::Result<()> {
+        let path = self.dir.join(format!("frame_{:04}.ppm", self.frames));
+        let mut out = BufWriter::new(File::create(path)?);
+        let (width, height) = (tiles.width() * self.scale, tiles.height() * self.scale);
+        write!(out, "P6\n{} {}\n255\n", width, height)?;
+        for y in 0..height {
+            for x in 0..width {
+                out.write_all(&color(tiles[Point::new(x / self.scale, y / self.scale)]))?;
+            }
+        }
+        out.flush()
+    }
+}
+
+impl Observer for PpmWriter {
+    fn frame(&mut self, tiles: &Grid<TileType>) {
+        if self.error.is_some() {
+            return;
+        }
+        match self.write(tiles) {
+            Ok(()) => self.frames += 1,
+            Err(e) => self.error = Some(e),
+        }
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        self.error.take().map_or(Ok(()), Err)
+    }
+}
+
+fn color(tile: TileType) -> [u8; 3] {
+    match tile {
+        TileType::Loop => [230, 180, 40],
+        TileType::Inside => [60, 170, 90],
+        TileType::Outside => [40, 40, 60],
+        TileType::Unknown => [120, 120, 120],
+    }
+}
+
+// records the frames as an asciinema v2 recording, `delay` apart, to replay with
+// `asciinema play`; like `PpmWriter` it stops at the first failed write and `finish` reports it
+pub struct CastWriter {
+    out: BufWriter<File>,
+    delay: Duration,
+    frames: usize,
+    error: Option<io::Error>,
+}
+
+impl CastWriter {
+    pub fn new(path: &Path, delay: Duration) -> io::Result<Self> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        Ok(Self {
+            out: BufWriter::new(File::create(path)?),
+            delay,
+            frames: 0,
+            error: None,
+        })
+    }
+
+    fn write(&mut self, tiles: &Grid<TileType>) -> io::Result<()> {
+        // the header needs the terminal size, which isn't known before the first frame
+        if self.frames == 0 {
+            writeln!(
+                self.out,
+                "{{\"version\": 2, \"width\": {}, \"height\": {}}}",
+                (tiles.width() * 2).saturating_sub(1),
+                tiles.height() + 1
+            )?;
+        }
+        let screen = format!("\x1b[2J\x1b[H{}\n", text(tiles)).replace('\n', "\r\n");
+        writeln!(
+            self.out,
+            "[{:.3}, \"o\", \"{}\"]",
+            (self.delay * self.frames as u32).as_secs_f64(),
+            json_escape(&screen)
+        )?;
+        self.out.flush()
+    }
+}
+
+impl Observer for CastWriter {
+    fn frame(&mut self, tiles: &Grid<TileType>) {
+        if self.error.is_some() {
+            return;
+        }
+        match self.write(tiles) {
+            Ok(()) => self.frames += 1,
+            Err(e) => self.error = Some(e),
+        }
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        match self.error.take() {
+            Some(e) => Err(e),
+            None => self.out.flush(),
+        }
+    }
+}
+
+fn json_escape(s: &str) -> String {
+    s.chars()
+        .map(|c| match c {
+            '"' => "\\\"".to_string(),
+            '\\' => "\\\\".to_string(),
+            c if c.is_control() => format!("\\u{:04x}", c as u32),
+            c => c.to_string(),
+        })
+        .collect()
+}
+
+// box-drawing pipes with the loop, the tiles inside it and the ones outside in their own colors
+const LOOP_COLOR: &str = "\x1b[1;33m";
+const START_COLOR: &str = "\x1b[1;31m";
+const INSIDE_COLOR: &str = "\x1b[32m";
+const OUTSIDE_COLOR: &str = "\x1b[2;34m";
+const RESET: &str = "\x1b[0m";
+
+// the maze as box-drawing pipes, the start tile as the pipe under it and the ground as dots;
+// pipes with only one end on the map are drawn as half a pipe. With `color` the loop, the
+// tiles inside it and the ones outside are told apart
+pub fn box_drawing(map: &mut Map, color: bool) -> String {
+    let regions = map.regions();
+    let start = map.start();
+    let drawing = regions
+        .rows()
+        .enumerate()
+        .map(|(y, row)| {
+            row.iter()
+                .enumerate()
+                .map(|(x, region)| {
+                    let pos = Point::new(x, y);
+                    let pipe = box_pipe(pos, map.connections(pos));
+                    if !color {
+                        return pipe.to_string();
+                    }
+                    let tint = match region {
+                        _ if Some(pos) == start => START_COLOR,
+                        TileType::Loop => LOOP_COLOR,
+                        TileType::Inside => INSIDE_COLOR,
+                        TileType::Outside | TileType::Unknown => OUTSIDE_COLOR,
+                    };
+                    format!("{}{}{}", tint, pipe, RESET)
+                })
+                .join("")
+        })
+        .join("\n");
+    drawing
+}
+
+fn box_pipe(pos: Point, ends: &[Point]) -> char {
+    let delta = |p: &Point| (p.x as isize - pos.x as isize, p.y as isize - pos.y as isize);
+    match ends {
+        [a, b] => match glyph(delta(a), delta(b)) {
+            '|' => '│',
+            '-' => '─',
+            'L' => '└',
+            'J' => '┘',
+            '7' => '┐',
+            _ => '┌',
+        },
+        [a] => match delta(a) {
+            (0, -1) => '╵',
+            (1, 0) => '╶',
+            (0, 1) => '╷',
+            _ => '╴',
+        },
+        _ => '·',
+    }
+}
@@ -0,0 +1,149 @@
+use crate::map::{pipe_ends, Map, StartError};
+use common::ParseError;
+use grid::{Grid, Point};
+use std::{collections::HashSet, fmt::Display};
+
+// anything in a maze besides the loop through the start tile and the ground
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Issue {
+    // a pipe leading off the map or into the ground
+    Dangling {
+        pos: Point,
+        tile: char,
+    },
+    // a pipe leading into one that doesn't lead back
+    OneWay {
+        from: Point,
+        to: Point,
+    },
+    // the start tile isn't on exactly one loop
+    Start {
+        pos: Option<Point>,
+        error: StartError,
+    },
+    // a loop of pipes that doesn't go through the start tile
+    ExtraLoop(Vec<Point>),
+    // a run of connected pipes that isn't a loop and never meets the start's loop
+    Unreachable(Vec<Point>),
+}
+
+// lines and columns count from 1, like `ParseError`s
+fn at(pos: Point) -> String {
+    format!("line {}, column {}", pos.y + 1, pos.x + 1)
+}
+
+impl Display for Issue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Issue::Dangling { pos, tile } => write!(
+                f,
+                "{}: '{}' leads off the map or into the ground",
+                at(*pos),
+                tile
+            ),
+            Issue::OneWay { from, to } => write!(
+                f,
+                "{}: leads into {}, which doesn't lead back",
+                at(*from),
+                at(*to)
+            ),
+            Issue::Start {
+                pos: Some(pos),
+                error,
+            } => write!(f, "{}: {} 'S'", at(*pos), error),
+            Issue::Start { pos: None, error } => write!(f, "{}", error),
+            Issue::ExtraLoop(tiles) => write!(
+                f,
+                "{}: loop of {} pipes away from the start",
+                at(tiles[0]),
+                tiles.len()
+            ),
+            Issue::Unreachable(tiles) => write!(
+                f,
+                "{}: run of {} pipe(s) the start's loop never reaches",
+                at(tiles[0]),
+                tiles.len()
+            ),
+        }
+    }
+}
+
+pub struct Report {
+    issues: Vec<Issue>,
+    cleaned: Grid<char>,
+}
+
+impl Report {
+    pub fn issues(&self) -> &[Issue] {
+        &self.issues
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    // the maze with only the start's loop left, every other pipe replaced by ground
+    pub fn cleaned(&self) -> &Grid<char> {
+        &self.cleaned
+    }
+}
+
+// checks every pipe of the maze, only tiles that aren't pipes, ground or a start fail to parse
+pub fn validate(input: &str) -> Result<Report, ParseError> {
+    let tiles: Grid<char> = Grid::parse(input, Some)?;
+    let mut map = Map::new(tiles.width(), tiles.height());
+    for (pos, chr) in tiles.iter() {
+        map.add_pos_from_char(pos, *chr)?;
+    }
+    let mut issues: Vec<Issue> = vec![];
+
+    // the start's pipe is worked out first, so pipes leading into it are checked against it
+    let start_inferred = map.infer_start();
+    let main_loop: HashSet<Point> = match &start_inferred {
+        Ok(_) => map.loop_tiles().clone(),
+        Err(_) => HashSet::new(),
+    };
+
+    for (pos, chr) in tiles.iter() {
+        let mut dangling = false;
+        for (dx, dy) in pipe_ends(*chr).unwrap_or_default() {
+            match pos.offset(*dx, *dy).filter(|p| tiles.contains(*p)) {
+                // without a pipe under the start there's nothing to check against
+                Some(to) if tiles[to] == 'S' && start_inferred.is_err() => {}
+                Some(to) if map.connections(to).contains(&pos) => {}
+                Some(to) if tiles[to] != '.' => issues.push(Issue::OneWay { from: pos, to }),
+                _ => dangling = true,
+            }
+        }
+        if dangling {
+            issues.push(Issue::Dangling { pos, tile: *chr });
+        }
+    }
+
+    if let Err(error) = start_inferred {
+        issues.push(Issue::Start {
+            pos: map.start(),
+            error,
+        });
+    }
+
+    // the other pipes split into runs of tiles that lead into each other both ways
+    for component in map.components() {
+        if main_loop.contains(&component.tiles[0]) {
+            continue;
+        }
+        match component.is_loop {
+            true => issues.push(Issue::ExtraLoop(component.tiles)),
+            false => issues.push(Issue::Unreachable(component.tiles)),
+        }
+    }
+
+    let mut cleaned = tiles.clone();
+    for (pos, chr) in tiles.iter() {
+        if *chr != 'S' && !main_loop.contains(&pos) {
+            cleaned[pos] = '.';
+        }
+    }
+
+    Ok(Report { issues, cleaned })
+}
@@ -0,0 +1,141 @@
+use common::Solution;
+use day_10::{
+    map::{AreaMethod, Map, StartError, TileType},
+    render::Observer,
+    Day10,
+};
+use grid::{Grid, Point};
+use std::{cell::RefCell, rc::Rc};
+
+#[test]
+fn finds_farthest_point_on_square_loop() {
+    let mut map = Day10::parse(include_str!("../resources/sample1.txt")).expect("parse sample");
+    assert_eq!(map.find_dist_to_farthest_pos(), 4);
+}
+
+#[test]
+fn finds_farthest_point_on_winding_loop() {
+    let mut map = Day10::parse(include_str!("../resources/sample2.txt")).expect("parse sample");
+    assert_eq!(map.find_dist_to_farthest_pos(), 8);
+}
+
+#[test]
+fn area_methods_agree_on_enclosed_tiles() {
+    let map = Day10::parse(include_str!("../resources/sample3.txt")).expect("parse sample");
+    for method in [AreaMethod::Scanline, AreaMethod::Shoelace] {
+        assert_eq!(map.clone().enclosed_area(method), 10, "{:?}", method);
+    }
+
+    // squeezing between pipes doesn't get outside tiles in
+    let squeezed = [
+        "..........",
+        ".S------7.",
+        ".|F----7|.",
+        ".||....||.",
+        ".||....||.",
+        ".|L-7F-J|.",
+        ".|..||..|.",
+        ".L--JL--J.",
+        "..........",
+    ]
+    .join("\n");
+    let map = Day10::parse(&squeezed).expect("parse map");
+    for method in [AreaMethod::Scanline, AreaMethod::Shoelace] {
+        assert_eq!(map.clone().enclosed_area(method), 4, "{:?}", method);
+    }
+}
+
+#[derive(Default)]
+struct Frames(Vec<Grid<TileType>>);
+
+impl Observer for Frames {
+    fn frame(&mut self, tiles: &Grid<TileType>) {
+        self.0.push(tiles.clone());
+    }
+}
+
+#[test]
+fn observer_sees_the_flood_fill_settle() {
+    let mut map = Day10::parse(include_str!("../resources/sample3.txt")).expect("parse sample");
+    let frames = Rc::new(RefCell::new(Frames::default()));
+    map.set_observer(frames.clone());
+    assert_eq!(map.enclosed_area(AreaMethod::FloodFill), 10);
+
+    let frames = frames.borrow();
+    assert!(frames.0.len() > 2);
+    let inside = |tiles: &Grid<TileType>| {
+        tiles
+            .iter()
+            .filter(|(_, t)| **t == TileType::Inside)
+            .count()
+    };
+    assert_eq!(inside(&frames.0[0]), 0);
+    assert_eq!(inside(frames.0.last().expect("a frame")), 10);
+}
+
+#[test]
+fn infers_the_pipe_under_the_start_tile() {
+    let map = Day10::parse(include_str!("../resources/sample1.txt")).expect("parse sample");
+    assert_eq!(map.start_glyph(), Some('F'));
+
+    // stray pipes next to and diagonal from the start don't count
+    let map = Day10::parse("7-F7-\n.FJ|7\nSJLL7\n|F--J\nLJ.LJ").expect("parse map");
+    assert_eq!(map.start_glyph(), Some('F'));
+}
+
+#[test]
+fn rejects_start_tiles_without_a_single_loop() {
+    let err = Day10::parse("...\n.S-\n.|.").err().expect("broken loop");
+    assert_eq!((err.line(), err.column(), err.token()), (2, 2, "S"));
+    assert_eq!(err.reason(), StartError::Broken.to_string());
+
+    let two_loops = ["F-7..", "|.|..", "L-S-7", "..|.|", "..L-J"].join("\n");
+    let mut map = Map::new(5, 5);
+    for (y, line) in two_loops.lines().enumerate() {
+        for (x, chr) in line.chars().enumerate() {
+            map.add_pos_from_char(Point::new(x, y), chr)
+                .expect("known tile");
+        }
+    }
+    match map.infer_start() {
+        Err(StartError::Ambiguous(mut glyphs)) => {
+            glyphs.sort();
+            assert_eq!(glyphs, vec!['F', 'J']);
+        }
+        other => panic!("expected two loops, got {:?}", other),
+    }
+}
+
+#[test]
+fn queries_distances_along_the_pipes() {
+    let map = Day10::parse(include_str!("../resources/sample1.txt")).expect("parse sample");
+    let (start, corner) = (Point::new(0, 0), Point::new(2, 2));
+
+    assert_eq!(map.distance(start, corner), Some(4));
+    assert_eq!(map.distance(Point::new(1, 0), Point::new(2, 1)), Some(2));
+    assert_eq!(map.distance(start, Point::new(1, 1)), None);
+
+    assert_eq!(map.farthest_from(&[start]), Some((corner, 4)));
+    // halfway between opposite corners, the first tile in reading order wins the tie
+    assert_eq!(
+        map.farthest_from(&[start, corner]),
+        Some((Point::new(2, 0), 2))
+    );
+}
+
+#[test]
+fn lists_every_group_of_linked_pipes() {
+    let map = Day10::parse("-S-7.\n.|.|F\n.L-J-\nF7...\nLJ..|").expect("parse map");
+    let components = map.components();
+
+    let loops: Vec<usize> = components
+        .iter()
+        .filter(|c| c.is_loop)
+        .map(|c| c.tiles.len())
+        .collect();
+    assert_eq!(loops, vec![8, 4]);
+    assert_eq!(components.len(), 6);
+    assert!(components
+        .iter()
+        .all(|c| !c.tiles.contains(&Point::new(2, 1))));
+}
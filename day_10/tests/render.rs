@@ -0,0 +1,76 @@
+use common::Solution;
+use day_10::{
+    map::AreaMethod,
+    render::{self, CastWriter, Observer, PpmWriter},
+    Day10,
+};
+use std::{cell::RefCell, env, fs, rc::Rc, time::Duration};
+
+#[test]
+fn exports_every_frame_as_images_and_a_cast() {
+    let dir = env::temp_dir().join(format!("day_10_frames_{}", std::process::id()));
+    let cast_path = dir.join("fill.cast");
+    let observers: Vec<Box<dyn Observer>> = vec![
+        Box::new(PpmWriter::new(&dir, 2).expect("create frames dir")),
+        Box::new(CastWriter::new(&cast_path, Duration::from_millis(500)).expect("create cast")),
+    ];
+
+    let mut map = Day10::parse(include_str!("../resources/sample3.txt")).expect("parse sample");
+    let observers = Rc::new(RefCell::new(observers));
+    map.set_observer(observers.clone());
+    assert_eq!(map.enclosed_area(AreaMethod::FloodFill), 10);
+    observers.borrow_mut().finish().expect("write every frame");
+
+    // the sample is 20 tiles by 10
+    let image = fs::read(dir.join("frame_0000.ppm")).expect("read first frame");
+    assert!(image.starts_with(b"P6\n40 20\n255\n"));
+    assert_eq!(image.len(), b"P6\n40 20\n255\n".len() + 40 * 20 * 3);
+
+    let cast = fs::read_to_string(&cast_path).expect("read cast");
+    let lines: Vec<&str> = cast.lines().collect();
+    assert_eq!(lines[0], r#"{"version": 2, "width": 39, "height": 11}"#);
+    assert!(lines[1].starts_with(r#"[0.000, "o", "\u001b[2J"#));
+    assert!(lines[2].starts_with("[0.500, "));
+
+    let images = fs::read_dir(&dir)
+        .expect("read frames dir")
+        .filter(|entry| {
+            let path = entry.as_ref().expect("dir entry").path();
+            path.extension().is_some_and(|ext| ext == "ppm")
+        })
+        .count();
+    assert_eq!(images, lines.len() - 1);
+
+    fs::remove_dir_all(&dir).expect("remove frames dir");
+}
+
+#[test]
+fn keeps_write_errors_for_finish() {
+    let dir = env::temp_dir().join(format!("day_10_gone_{}", std::process::id()));
+    let writer = Rc::new(RefCell::new(
+        PpmWriter::new(&dir, 1).expect("create frames dir"),
+    ));
+    fs::remove_dir_all(&dir).expect("remove frames dir");
+
+    let mut map = Day10::parse(include_str!("../resources/sample3.txt")).expect("parse sample");
+    map.set_observer(writer.clone());
+    assert_eq!(map.enclosed_area(AreaMethod::FloodFill), 10);
+
+    assert!(writer.borrow_mut().finish().is_err());
+    // the error is only reported once
+    assert!(writer.borrow_mut().finish().is_ok());
+}
+
+#[test]
+fn draws_pipes_with_box_drawing_glyphs() {
+    let mut map = Day10::parse(include_str!("../resources/sample2.txt")).expect("parse sample");
+    assert_eq!(
+        render::box_drawing(&mut map, false),
+        ["··┌┐·", "·┌┘│·", "┌┘·└┐", "│┌──┘", "└┘···"].join("\n")
+    );
+
+    // the start, the loop and the one tile inside it each get their own color
+    let colored = render::box_drawing(&mut map, true);
+    let line = colored.lines().nth(2).expect("third row");
+    assert!(line.starts_with("\x1b[1;31m┌\x1b[0m\x1b[1;33m┘\x1b[0m\x1b[32m·\x1b[0m"));
+}
@@ -0,0 +1,47 @@
+use day_10::validate::{validate, Issue};
+use grid::Point;
+
+#[test]
+fn puzzle_samples_are_valid() {
+    let report = validate(include_str!("../resources/sample2.txt")).expect("parse sample");
+    assert!(report.is_valid(), "{:?}", report.issues());
+}
+
+#[test]
+fn lists_junk_pipes_and_cleans_them_away() {
+    let maze = ["-S-7.", ".|.|F", ".L-J-", "F7...", "LJ..|"].join("\n");
+    let report = validate(&maze).expect("parse maze");
+    let issues = report.issues();
+
+    assert!(issues.contains(&Issue::Dangling {
+        pos: Point::new(0, 0),
+        tile: '-'
+    }));
+    assert!(issues.contains(&Issue::OneWay {
+        from: Point::new(4, 1),
+        to: Point::new(4, 2)
+    }));
+    // the start is an `F`, so the pipe left of it leads in one way only
+    assert!(issues.contains(&Issue::OneWay {
+        from: Point::new(0, 0),
+        to: Point::new(1, 0)
+    }));
+    // open at both ends, reported once
+    let open_ends = issues
+        .iter()
+        .filter(|issue| matches!(issue, Issue::Dangling { pos, .. } if *pos == Point::new(4, 4)))
+        .count();
+    assert_eq!(open_ends, 1);
+    assert!(issues.contains(&Issue::ExtraLoop(vec![
+        Point::new(0, 3),
+        Point::new(1, 3),
+        Point::new(0, 4),
+        Point::new(1, 4),
+    ])));
+    assert!(issues.contains(&Issue::Unreachable(vec![Point::new(4, 4)])));
+
+    assert_eq!(
+        report.cleaned().to_string(),
+        [".S-7.", ".|.|.", ".L-J.", ".....", "....."].join("\n")
+    );
+}
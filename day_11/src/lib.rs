@@ -0,0 +1,70 @@
+use common::{ParseError, Solution};
+use grid::Grid;
+
+static SMALL_EXPANSION_DIST: usize = 2;
+static EXPANSION_DIST: usize = 1000000;
+
+// `true` where there's a galaxy
+pub struct Universe {
+    image: Grid<bool>,
+}
+
+impl Universe {
+    // sum of the distances between every pair of galaxies, with each empty row and column
+    // growing to `expansion_dist` rows or columns; the distances split into their x and y
+    // parts, which are summed an axis at a time
+    pub fn sum_of_shortest_paths(&self, expansion_dist: usize) -> usize {
+        let growth = expansion_dist.saturating_sub(1);
+        let per_row = self
+            .image
+            .rows()
+            .map(|row| row.iter().filter(|galaxy| **galaxy).count());
+        let per_col =
+            (0..self.image.width()).map(|x| self.image.col(x).filter(|galaxy| **galaxy).count());
+
+        axis_distance_sum(per_row, growth) + axis_distance_sum(per_col, growth)
+    }
+}
+
+// the distances along one axis between every pair of galaxies, given how many galaxies are in
+// each row (or column) in order; an empty one pushes the rest `growth` further along. Going in
+// order, a galaxy at `coord` is `coord - c` past each galaxy before it at `c`, so all of those
+// together are `seen * coord - coord_sum` away
+fn axis_distance_sum(counts: impl Iterator<Item = usize>, growth: usize) -> usize {
+    let (mut coord, mut seen, mut coord_sum, mut total) = (0, 0, 0, 0);
+    for count in counts {
+        if count == 0 {
+            coord += growth;
+        }
+        total += count * (seen * coord - coord_sum);
+        seen += count;
+        coord_sum += count * coord;
+        coord += 1;
+    }
+    total
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Universe;
+    type Output = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let image = Grid::parse(input, |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })?;
+
+        Ok(Universe { image })
+    }
+
+    fn part1(input: &Self::Input) -> Self::Output {
+        input.sum_of_shortest_paths(SMALL_EXPANSION_DIST)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Output {
+        input.sum_of_shortest_paths(EXPANSION_DIST)
+    }
+}
@@ -0,0 +1,38 @@
+use common::Solution;
+use day_11::Day11;
+
+#[test]
+fn sums_distances_for_any_expansion() {
+    let universe = Day11::parse(include_str!("../resources/sample.txt")).expect("parse sample");
+    assert_eq!(universe.sum_of_shortest_paths(2), 374);
+    assert_eq!(universe.sum_of_shortest_paths(10), 1030);
+    assert_eq!(universe.sum_of_shortest_paths(100), 8410);
+}
+
+#[test]
+fn galaxies_in_a_line_only_differ_along_it() {
+    // at columns 0, 1, 4 and 9 once the three empty columns double
+    let universe = Day11::parse("##.#..#").expect("parse universe");
+    assert_eq!(universe.sum_of_shortest_paths(2), 1 + 4 + 9 + 3 + 8 + 5);
+}
+
+#[test]
+fn no_expansion_leaves_plain_manhattan_distances() {
+    let input = include_str!("../resources/sample.txt");
+    let universe = Day11::parse(input).expect("parse sample");
+    let galaxies: Vec<(usize, usize)> = input
+        .lines()
+        .enumerate()
+        .flat_map(|(y, line)| line.match_indices('#').map(move |(x, _)| (x, y)))
+        .collect();
+    let manhattan: usize = galaxies
+        .iter()
+        .enumerate()
+        .flat_map(|(i, g1)| galaxies[i + 1..].iter().map(move |g2| (g1, g2)))
+        .map(|(g1, g2)| g1.0.abs_diff(g2.0) + g1.1.abs_diff(g2.1))
+        .sum();
+
+    assert_eq!(universe.sum_of_shortest_paths(1), manhattan);
+    // a factor of 0 can't shrink empty space away
+    assert_eq!(universe.sum_of_shortest_paths(0), manhattan);
+}
@@ -0,0 +1,248 @@
+use common::{parse, ParseError, Solution};
+use itertools::Itertools;
+use std::iter;
+
+// part 2 unfolds every record into five copies joined by unknown springs
+pub const SCHEMATIC_COPIES: usize = 5;
+pub const SCHEMATIC_SEPARATOR: Option<Condition> = Some(Condition::Unknown);
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Condition {
+    Operational,
+    Damaged,
+    Unknown,
+}
+
+impl From<char> for Condition {
+    fn from(value: char) -> Self {
+        match value {
+            '.' => Self::Operational,
+            '#' => Self::Damaged,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+pub fn format_conds(conds: &[Condition]) -> String {
+    conds
+        .iter()
+        .map(|c| match c {
+            Condition::Operational => '.',
+            Condition::Damaged => '#',
+            Condition::Unknown => '?',
+        })
+        .join("")
+}
+
+pub fn print_conds(conds: &[Condition]) {
+    println!("{}", format_conds(conds));
+}
+
+// a group can only start at `start` if none of its springs are known to be operational and
+// neither of the springs bordering it are known to be damaged
+fn group_fits(schematic: &[Condition], start: usize, size: usize) -> bool {
+    let end = start + size;
+    end <= schematic.len()
+        && schematic[start..end]
+            .iter()
+            .all(|c| *c != Condition::Operational)
+        && (start == 0 || schematic[start - 1] != Condition::Damaged)
+        && schematic.get(end) != Some(&Condition::Damaged)
+}
+
+pub struct Record {
+    schematic: Vec<Condition>,
+    groups: Vec<usize>,
+}
+
+impl Record {
+    pub fn new(schematic: Vec<Condition>, groups: Vec<usize>) -> Self {
+        Self { schematic, groups }
+    }
+
+    // `copies` of the record back to back, with `separator` between each copy of the schematic
+    pub fn unfold(&self, copies: usize, separator: Option<Condition>) -> Record {
+        Record {
+            schematic: iter::repeat_n(&self.schematic, copies)
+                .map(|conds| conds.to_vec())
+                .reduce(|mut acc, conds| {
+                    acc.extend(separator);
+                    acc.extend(conds);
+                    acc
+                })
+                .unwrap_or_default(),
+            groups: iter::repeat_n(self.groups.iter().copied(), copies)
+                .flatten()
+                .collect_vec(),
+        }
+    }
+
+    // `ways[pos][group]` is how many ways the groups from `group` on fit into the springs from
+    // `pos` on
+    fn ways(&self) -> Vec<Vec<usize>> {
+        let (springs, groups) = (self.schematic.len(), self.groups.len());
+        let mut ways: Vec<Vec<usize>> = vec![vec![0; groups + 1]; springs + 1];
+        ways[springs][groups] = 1;
+
+        for pos in (0..springs).rev() {
+            for group in 0..=groups {
+                if self.can_be_operational(pos) {
+                    ways[pos][group] += ways[pos + 1][group];
+                }
+                if let Some(next) = self.group_end(pos, group) {
+                    ways[pos][group] += ways[next][group + 1];
+                }
+            }
+        }
+
+        ways
+    }
+
+    // the spring is operational, move on to the next one
+    fn can_be_operational(&self, pos: usize) -> bool {
+        self.schematic[pos] != Condition::Damaged
+    }
+
+    // if the group can start at `pos`, where to carry on after it and the operational spring
+    // following it
+    fn group_end(&self, pos: usize, group: usize) -> Option<usize> {
+        let size = *self.groups.get(group)?;
+        match self.schematic[pos] != Condition::Operational
+            && group_fits(&self.schematic, pos, size)
+        {
+            true => Some((pos + size + 1).min(self.schematic.len())),
+            false => None,
+        }
+    }
+
+    // counts arrangements without building them
+    pub fn count_arrangements(&self) -> usize {
+        self.ways()[0][0]
+    }
+
+    // lazily yields each arrangement, at most `limit` of them, in the order their strings sort
+    // (groups as far left as they go first)
+    pub fn arrangements(&self, limit: Option<usize>) -> impl Iterator<Item = Vec<Condition>> + '_ {
+        Arrangements {
+            record: self,
+            ways: self.ways(),
+            stack: vec![(0, 0, vec![])],
+        }
+        .take(limit.unwrap_or(usize::MAX))
+    }
+
+    // the schematic followed by its arrangements, one per line
+    pub fn print_arrangements(&self, limit: Option<usize>) {
+        print_conds(&self.schematic);
+        let mut shown = 0;
+        for arrangement in self.arrangements(limit) {
+            print_conds(&arrangement);
+            shown += 1;
+        }
+        println!("{} of {} arrangements", shown, self.count_arrangements());
+    }
+}
+
+// depth first walk of the choices at each spring, only stepping into choices the `ways` table
+// says lead to at least one arrangement, so every finished walk is yielded
+struct Arrangements<'a> {
+    record: &'a Record,
+    ways: Vec<Vec<usize>>,
+    // (pos, group, springs decided so far)
+    stack: Vec<(usize, usize, Vec<Condition>)>,
+}
+
+impl Iterator for Arrangements<'_> {
+    type Item = Vec<Condition>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((pos, group, conds)) = self.stack.pop() {
+            if pos == self.record.schematic.len() {
+                if self.ways[pos][group] > 0 {
+                    return Some(conds);
+                }
+                continue;
+            }
+
+            // '.' goes on the stack first so the '#' choice comes off it first
+            if self.record.can_be_operational(pos) && self.ways[pos + 1][group] > 0 {
+                let mut operational = conds.clone();
+                operational.push(Condition::Operational);
+                self.stack.push((pos + 1, group, operational));
+            }
+            if let Some(next) = self.record.group_end(pos, group) {
+                if self.ways[next][group + 1] > 0 {
+                    let mut damaged = conds;
+                    damaged.extend(iter::repeat_n(
+                        Condition::Damaged,
+                        self.record.groups[group],
+                    ));
+                    damaged.resize(next, Condition::Operational);
+                    self.stack.push((next, group + 1, damaged));
+                }
+            }
+        }
+        None
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<Record>;
+    type Output = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                let Some((spring_string, group_string)) = line.split_once(' ') else {
+                    return Err(ParseError::in_line(
+                        i,
+                        line,
+                        line,
+                        "expected '<springs> <groups>', found",
+                    ));
+                };
+                if let Some((col, c)) = spring_string
+                    .char_indices()
+                    .find(|(_, c)| !".#?".contains(*c))
+                {
+                    let spring = &spring_string[col..col + c.len_utf8()];
+                    return Err(ParseError::in_line(i, line, spring, "unexpected spring"));
+                }
+                Ok(Record {
+                    schematic: spring_string.chars().map(Condition::from).collect_vec(),
+                    groups: group_string
+                        .split(',')
+                        .map(|c| match parse::number(i, line, c)? {
+                            0 => Err(ParseError::in_line(i, line, c, "empty group")),
+                            size => Ok(size),
+                        })
+                        .collect::<Result<_, _>>()?,
+                })
+            })
+            .collect()
+    }
+
+    fn part1(input: &Self::Input) -> Self::Output {
+        input.iter().map(Record::count_arrangements).sum()
+    }
+
+    fn part2(input: &Self::Input) -> Self::Output {
+        total_unfolded_arrangements(input, SCHEMATIC_COPIES, SCHEMATIC_SEPARATOR)
+    }
+}
+
+// the arrangements of every record after unfolding it, see `Record::unfold`
+pub fn total_unfolded_arrangements(
+    records: &[Record],
+    copies: usize,
+    separator: Option<Condition>,
+) -> usize {
+    records
+        .iter()
+        .map(|record| record.unfold(copies, separator).count_arrangements())
+        .sum()
+}
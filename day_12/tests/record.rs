@@ -0,0 +1,86 @@
+use day_12::{format_conds, Condition, Record};
+
+fn record(springs: &str, groups: &[usize]) -> Record {
+    Record::new(
+        springs.chars().map(Condition::from).collect(),
+        groups.to_vec(),
+    )
+}
+
+#[test]
+fn parses_spring_conditions() {
+    assert_eq!(Condition::from('.'), Condition::Operational);
+    assert_eq!(Condition::from('#'), Condition::Damaged);
+    assert_eq!(Condition::from('?'), Condition::Unknown);
+}
+
+#[test]
+fn counts_arrangements() {
+    assert_eq!(record("???.###", &[1, 1, 3]).count_arrangements(), 1);
+    assert_eq!(record(".??..??...?##.", &[1, 1, 3]).count_arrangements(), 4);
+    assert_eq!(
+        record("?#?#?#?#?#?#?#?", &[1, 3, 1, 6]).count_arrangements(),
+        1
+    );
+    assert_eq!(record("????.#...#...", &[4, 1, 1]).count_arrangements(), 1);
+    assert_eq!(
+        record("????.######..#####.", &[1, 6, 5]).count_arrangements(),
+        4
+    );
+    assert_eq!(record("?###????????", &[3, 2, 1]).count_arrangements(), 10);
+}
+
+#[test]
+fn unfolds_with_separators_only_between_copies() {
+    let unfolded = record(".#", &[1]).unfold(3, Some(Condition::Unknown));
+    assert_eq!(unfolded.count_arrangements(), 1);
+
+    assert_eq!(
+        record("???.###", &[1, 1, 3])
+            .unfold(5, Some(Condition::Unknown))
+            .count_arrangements(),
+        1
+    );
+    assert_eq!(
+        record("?###????????", &[3, 2, 1])
+            .unfold(5, Some(Condition::Unknown))
+            .count_arrangements(),
+        506250
+    );
+    assert_eq!(record("???", &[1]).unfold(2, None).count_arrangements(), 10);
+}
+
+#[test]
+fn counts_without_building_every_arrangement() {
+    // 20 single springs spread over 60 slots, C(41, 20) ways
+    let springs = "?".repeat(60);
+    assert_eq!(
+        record(&springs, &[1; 20]).count_arrangements(),
+        269128937220
+    );
+}
+
+#[test]
+fn lists_arrangements_in_order() {
+    let arrangements: Vec<String> = record("?###????????", &[3, 2, 1])
+        .arrangements(None)
+        .map(|conds| format_conds(&conds))
+        .collect();
+
+    assert_eq!(arrangements.len(), 10);
+    assert_eq!(arrangements[0], ".###.##.#...");
+    assert_eq!(arrangements[9], ".###....##.#");
+    assert!(arrangements.windows(2).all(|w| w[0] < w[1]));
+}
+
+#[test]
+fn arrangements_stop_at_the_limit() {
+    let springs = "?".repeat(60);
+    let record = record(&springs, &[1; 20]);
+
+    assert_eq!(record.arrangements(Some(3)).count(), 3);
+    assert_eq!(
+        format_conds(&record.arrangements(Some(1)).next().expect("an arrangement")),
+        "#.".repeat(20) + &".".repeat(20)
+    );
+}
@@ -0,0 +1,110 @@
+use common::{ParseError, Solution};
+use grid::Grid;
+
+#[derive(PartialEq, Eq, Hash, Debug, Clone, Copy)]
+enum Item {
+    Ash,
+    Rock,
+}
+
+pub struct Pattern {
+    grid: Grid<Item>,
+}
+
+impl Pattern {
+    // returns the number of differences between two rows
+    fn count_differences(row0: &[Item], row1: &[Item]) -> usize {
+        row0.iter()
+            .zip(row1.iter())
+            .filter(|(i0, i1)| i0 != i1)
+            .count()
+    }
+
+    // the row `y` that mirrors the grid onto row `y + 1` with exactly `smudges` differences,
+    // ignoring the rows reflected off the edge
+    fn find_mirror_row(grid: &Grid<Item>, smudges: usize) -> Option<usize> {
+        (0..grid.height().saturating_sub(1)).find(|y| {
+            let mut differences = 0;
+            for (r0, r1) in (0..=*y).rev().zip((y + 1)..grid.height()) {
+                differences += Pattern::count_differences(grid.row(r0), grid.row(r1));
+                if differences > smudges {
+                    return false;
+                }
+            }
+            differences == smudges
+        })
+    }
+
+    // finds the line of symmetry that's off by exactly `smudges` differences
+    pub fn find_sym_line(&self, smudges: usize) -> Option<(usize, usize)> {
+        // columns are the rows of the transposed pattern
+        if let Some(x) = Pattern::find_mirror_row(&self.grid.transpose(), smudges) {
+            return Some((0, x));
+        }
+        if let Some(y) = Pattern::find_mirror_row(&self.grid, smudges) {
+            return Some((1, y));
+        }
+        return None;
+    }
+}
+
+impl Pattern {
+    pub fn summarize(&self, smudges: usize) -> usize {
+        let (axis, idx) = self
+            .find_sym_line(smudges)
+            .expect("parse checked every pattern has a mirror line");
+        match axis {
+            1 => (idx + 1) * 100,
+            0 => idx + 1,
+            other => panic!("unexpected axis: {:?}", other),
+        }
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Pattern>;
+    type Output = usize;
+
+    // patterns are separated by blank lines, and each one needs a clean mirror line for part 1
+    // and a smudged one for part 2
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let lines: Vec<(usize, &str)> = input.lines().enumerate().collect();
+
+        lines
+            .split(|(_, row)| row.is_empty())
+            .filter(|rows| !rows.is_empty())
+            .map(|rows| {
+                let grid = Grid::parse_lines(rows.iter().copied(), |c| match c {
+                    '.' => Some(Item::Ash),
+                    '#' => Some(Item::Rock),
+                    _ => None,
+                })?;
+                let pattern = Pattern { grid };
+                if let Some(smudges) = (0..=1).find(|s| pattern.find_sym_line(*s).is_none()) {
+                    let (i, line) = rows[0];
+                    return Err(ParseError::in_line(
+                        i,
+                        line,
+                        line,
+                        format!(
+                            "no mirror line with {} smudges in pattern starting",
+                            smudges
+                        ),
+                    ));
+                }
+                Ok(pattern)
+            })
+            .collect()
+    }
+
+    fn part1(input: &Self::Input) -> Self::Output {
+        input.iter().map(|pattern| pattern.summarize(0)).sum()
+    }
+
+    // every pattern has exactly one smudge on its mirror
+    fn part2(input: &Self::Input) -> Self::Output {
+        input.iter().map(|pattern| pattern.summarize(1)).sum()
+    }
+}
@@ -0,0 +1,179 @@
+pub mod tilts;
+pub mod visualize;
+
+use common::{cycle, ParseError, Solution};
+use grid::{Grid, Point};
+use itertools::Itertools;
+use std::fmt::Display;
+use tilts::Tilts;
+
+// part 2 spins the dish a billion times
+const SPIN_CYCLES: &str = "NWSE*1000000000";
+
+// a byte per cell, the dish is one flat run of them
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy)]
+#[repr(u8)]
+enum Item {
+    Boulder,
+    Wall,
+    Empty,
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl TryFrom<char> for Direction {
+    type Error = char;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'N' => Ok(Direction::North),
+            'E' => Ok(Direction::East),
+            'S' => Ok(Direction::South),
+            'W' => Ok(Direction::West),
+            other => Err(other),
+        }
+    }
+}
+
+// hashes by where every boulder is, so repeated states can be spotted
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Dish {
+    grid: Grid<Item>,
+}
+
+impl Dish {
+    // tilting compacts every column (or row) towards the edge it's tilted to: boulders slide
+    // into the free cells nearest that edge and a wall starts a new run of free cells past it
+    pub fn roll_in_direction(&mut self, dir: Direction) {
+        let (lines, line_len) = match dir {
+            Direction::North | Direction::South => (self.grid.width(), self.grid.height()),
+            Direction::East | Direction::West => (self.grid.height(), self.grid.width()),
+        };
+
+        for line in 0..lines {
+            let mut free = 0;
+            for k in 0..line_len {
+                let pnt = self.point_on_line(&dir, line, k);
+                match self.grid[pnt] {
+                    Item::Wall => free = k + 1,
+                    Item::Boulder => {
+                        if free != k {
+                            self.grid[pnt] = Item::Empty;
+                            let dest = self.point_on_line(&dir, line, free);
+                            self.grid[dest] = Item::Boulder;
+                        }
+                        free += 1;
+                    }
+                    Item::Empty => (),
+                }
+            }
+        }
+    }
+
+    // the `k`th cell of a column (or row), counting from the edge the dish is tilted to
+    fn point_on_line(&self, dir: &Direction, line: usize, k: usize) -> Point {
+        match dir {
+            Direction::North => Point::new(line, k),
+            Direction::South => Point::new(line, self.grid.height() - 1 - k),
+            Direction::West => Point::new(k, line),
+            Direction::East => Point::new(self.grid.width() - 1 - k, line),
+        }
+    }
+
+    // every run of tilts in turn, repeated runs skip ahead once the dish starts repeating
+    pub fn tilt(&mut self, tilts: &Tilts) {
+        for (dirs, count) in tilts.runs() {
+            let dish = self.clone();
+            *self = cycle::nth_state(dish, *count, |dish| {
+                let mut next = dish.clone();
+                for dir in dirs {
+                    next.roll_in_direction(*dir);
+                }
+                next
+            });
+        }
+    }
+
+    // each boulder weighs on the `dir` side as many cells as it is from the opposite edge,
+    // counting its own
+    pub fn load(&self, dir: Direction) -> usize {
+        let (width, height) = (self.grid.width(), self.grid.height());
+        self.grid
+            .iter()
+            .filter(|(_, i)| **i == Item::Boulder)
+            .map(|(p, _)| match dir {
+                Direction::North => height - p.y,
+                Direction::South => p.y + 1,
+                Direction::West => width - p.x,
+                Direction::East => p.x + 1,
+            })
+            .sum()
+    }
+}
+
+impl Display for Dish {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let t = self
+            .grid
+            .rows()
+            .map(|row| {
+                row.iter()
+                    .map(|i| match i {
+                        Item::Boulder => 'O',
+                        Item::Wall => '#',
+                        Item::Empty => '.',
+                    })
+                    .join(" ")
+            })
+            .join("\n");
+        write!(f, "{}", t)
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Dish;
+    type Output = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let grid = Grid::parse_lines(
+            input.lines().enumerate().filter(|(_, row)| !row.is_empty()),
+            |c| match c {
+                'O' => Some(Item::Boulder),
+                '#' => Some(Item::Wall),
+                '.' => Some(Item::Empty),
+                _ => None,
+            },
+        )?;
+
+        Ok(Dish { grid })
+    }
+
+    fn part1(input: &Self::Input) -> Self::Output {
+        tilted_load(input, &"N".parse().expect("valid tilts"), Direction::North)
+    }
+
+    // the spin cycles settle into a loop long before the billionth, so the state that many
+    // cycles in is the one at the same place in the loop
+    fn part2(input: &Self::Input) -> Self::Output {
+        tilted_load(
+            input,
+            &SPIN_CYCLES.parse().expect("valid tilts"),
+            Direction::North,
+        )
+    }
+}
+
+// the `dir` load after tilting a copy of the dish by `tilts`
+pub fn tilted_load(dish: &Dish, tilts: &Tilts, dir: Direction) -> usize {
+    let mut dish = dish.clone();
+    dish.tilt(tilts);
+    dish.load(dir)
+}
@@ -0,0 +1,65 @@
+use crate::Direction;
+use common::ParseError;
+use std::str::FromStr;
+
+// a sequence of tilts like "NWSE*1000000000" or "N EEW": runs of directions, each optionally
+// repeated `*count` times
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tilts {
+    runs: Vec<(Vec<Direction>, usize)>,
+}
+
+impl Tilts {
+    pub fn runs(&self) -> &[(Vec<Direction>, usize)] {
+        &self.runs
+    }
+}
+
+impl FromStr for Tilts {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut runs: Vec<(Vec<Direction>, usize)> = vec![];
+        let mut dirs: Vec<Direction> = vec![];
+        let mut chars = s.char_indices().peekable();
+
+        while let Some((col, c)) = chars.next() {
+            let token = &s[col..col + c.len_utf8()];
+            match c {
+                'N' | 'E' | 'S' | 'W' => dirs.push(Direction::try_from(c).expect("direction char")),
+                '*' => {
+                    let start = col + 1;
+                    let mut end = start;
+                    while let Some((i, d)) = chars.next_if(|(_, d)| d.is_ascii_digit()) {
+                        end = i + d.len_utf8();
+                    }
+                    let count = common::parse::number(0, s, &s[start..end])?;
+                    if dirs.is_empty() {
+                        return Err(ParseError::in_line(0, s, token, "nothing to repeat before"));
+                    }
+                    runs.push((dirs, count));
+                    dirs = vec![];
+                }
+                c if c.is_whitespace() || c == ',' => {
+                    if !dirs.is_empty() {
+                        runs.push((dirs, 1));
+                        dirs = vec![];
+                    }
+                }
+                _ => {
+                    return Err(ParseError::in_line(
+                        0,
+                        s,
+                        token,
+                        "expected a direction (N, E, S or W) or '*', found",
+                    ))
+                }
+            }
+        }
+        if !dirs.is_empty() {
+            runs.push((dirs, 1));
+        }
+
+        Ok(Self { runs })
+    }
+}
@@ -0,0 +1,96 @@
+use crate::{tilts::Tilts, Direction, Dish, Item};
+use common::cycle;
+use itertools::Itertools;
+use std::{thread, time::Duration};
+
+pub const DEFAULT_DELAY: Duration = Duration::from_millis(200);
+
+// bold yellow for boulders that moved in the last tilt
+const HIGHLIGHT: &str = "\x1b[1;33m";
+const RESET: &str = "\x1b[0m";
+
+// the dish as `Display` draws it, with every boulder that wasn't in the same cell of `before`
+// highlighted
+pub fn render(dish: &Dish, before: &Dish) -> String {
+    dish.grid
+        .rows()
+        .zip(before.grid.rows())
+        .map(|(row, prev_row)| {
+            row.iter()
+                .zip(prev_row.iter())
+                .map(|(i, prev)| match (i, prev) {
+                    (Item::Boulder, Item::Boulder) => "O".to_string(),
+                    (Item::Boulder, _) => format!("{}O{}", HIGHLIGHT, RESET),
+                    (Item::Wall, _) => "#".to_string(),
+                    (Item::Empty, _) => ".".to_string(),
+                })
+                .join(" ")
+        })
+        .join("\n")
+}
+
+// tilts the dish one direction at a time, redrawing it after each tilt; once a repeated run
+// brings the dish back to a state it was in after an earlier repetition the loop is reported
+// and only the repetitions left over after going round it whole are drawn
+pub fn animate(dish: &mut Dish, tilts: &Tilts, delay: Duration) {
+    let mut frame = 0;
+    draw(dish, dish, "start", delay);
+
+    for (dirs, count) in tilts.runs() {
+        let mut rep = 0;
+        let mut run = |dish: &Dish, note: &str, rep: &mut usize| {
+            let mut next = dish.clone();
+            for dir in dirs {
+                let before = next.clone();
+                next.roll_in_direction(*dir);
+                frame += 1;
+                let caption = format!(
+                    "tilt {} ({}), repetition {} of {}{}",
+                    frame,
+                    letter(dir),
+                    *rep + 1,
+                    count,
+                    note
+                );
+                draw(&next, &before, &caption, delay);
+            }
+            *rep += 1;
+            next
+        };
+
+        let (found, _, last) =
+            cycle::find_cycle_within(dish.clone(), Some(*count), |d| run(d, "", &mut rep));
+        *dish = last;
+        if let Some(found) = found {
+            let left = (count - rep) % found.period;
+            let note = format!(
+                ", looping every {} repetition(s) since {}, {} left after skipping ahead",
+                found.period, found.start, left
+            );
+            rep = count - left;
+            for _ in 0..left {
+                *dish = run(dish, &note, &mut rep);
+            }
+        }
+    }
+}
+
+fn letter(dir: &Direction) -> char {
+    match dir {
+        Direction::North => 'N',
+        Direction::East => 'E',
+        Direction::South => 'S',
+        Direction::West => 'W',
+    }
+}
+
+fn draw(dish: &Dish, before: &Dish, caption: &str, delay: Duration) {
+    thread::sleep(delay);
+    common::term::clear();
+    println!(
+        "{}\n\n{}, north load {}\n",
+        render(dish, before),
+        caption,
+        dish.load(Direction::North)
+    );
+}
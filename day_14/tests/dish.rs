@@ -0,0 +1,87 @@
+use common::Solution;
+use day_14::{tilts::Tilts, visualize, Day14, Direction, Dish};
+
+fn sample() -> Dish {
+    Day14::parse(include_str!("../resources/sample.txt")).expect("parse sample")
+}
+
+// `Dish` spaces its cells out when printed
+fn layout(dish: &Dish) -> String {
+    dish.to_string().replace(' ', "")
+}
+
+#[test]
+fn tilting_north_stacks_boulders_against_walls() {
+    let mut dish = sample();
+    dish.roll_in_direction(Direction::North);
+
+    assert_eq!(
+        layout(&dish).lines().take(3).collect::<Vec<_>>(),
+        vec!["OOOO.#.O..", "OO..#....#", "OO..O##..O"]
+    );
+    assert_eq!(dish.load(Direction::North), 136);
+}
+
+#[test]
+fn spin_cycle_tilts_every_way() {
+    let mut dish = sample();
+    dish.tilt(&"NWSE".parse().expect("valid tilts"));
+
+    assert_eq!(
+        layout(&dish),
+        [
+            ".....#....",
+            "....#...O#",
+            "...OO##...",
+            ".OO#......",
+            ".....OOO#.",
+            ".O#...O#.#",
+            "....O#....",
+            "......OOOO",
+            "#...O###..",
+            "#..OO#....",
+        ]
+        .join("\n")
+    );
+}
+
+#[test]
+fn tilt_sequences_and_loads_in_any_direction() {
+    let tilts = |seq: &str| seq.parse::<Tilts>().expect("valid tilts");
+    assert_eq!(
+        day_14::tilted_load(&sample(), &tilts("N"), Direction::North),
+        136
+    );
+    assert_eq!(
+        day_14::tilted_load(&sample(), &tilts("NWSE*1000000000"), Direction::North),
+        64
+    );
+
+    // tilting the same way twice changes nothing
+    let mut once = sample();
+    once.tilt(&"E".parse().expect("valid tilts"));
+    let mut twice = sample();
+    twice.tilt(&"EE W*3, E".parse().expect("valid tilts"));
+    assert_eq!(layout(&once), layout(&twice));
+    assert_eq!(once.load(Direction::East), twice.load(Direction::East));
+}
+
+#[test]
+fn rejects_unknown_tilts() {
+    let err = "NWX".parse::<Tilts>().unwrap_err();
+    assert_eq!((err.column(), err.token()), (3, "X"));
+
+    let err = "*4".parse::<Tilts>().unwrap_err();
+    assert_eq!((err.column(), err.token()), (1, "*"));
+}
+
+#[test]
+fn highlights_only_moved_boulders() {
+    let before = Day14::parse("O.\n.O").expect("parse dish");
+    let mut after = before.clone();
+    after.roll_in_direction(Direction::North);
+
+    let frame = visualize::render(&after, &before);
+    assert_eq!(frame, "O \x1b[1;33mO\x1b[0m\n. .");
+    assert_eq!(visualize::render(&after, &after), "O O\n. .");
+}
@@ -0,0 +1,94 @@
+use common::{ParseError, Solution};
+use std::collections::HashMap;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<String>;
+    type Output = usize;
+
+    // steps are "<label>=<focal len>" or "<label>-", separated by commas
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut steps: Vec<String> = vec![];
+        for (i, line) in input.lines().enumerate() {
+            for step in line.split(',').filter(|step| !step.is_empty()) {
+                let valid = match step.split_once('=') {
+                    Some((label, focal_len)) => {
+                        !label.is_empty() && focal_len.parse::<usize>().is_ok()
+                    }
+                    None => step.len() > 1 && step.ends_with('-'),
+                };
+                if !valid {
+                    return Err(ParseError::in_line(
+                        i,
+                        line,
+                        step,
+                        "expected '<label>=<focal len>' or '<label>-', found",
+                    ));
+                }
+                steps.push(step.to_string());
+            }
+        }
+        Ok(steps)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Output {
+        input.iter().map(|step| hash_alg(step)).sum()
+    }
+
+    fn part2(input: &Self::Input) -> Self::Output {
+        let mut boxes: HashMap<usize, Vec<(String, usize)>> = HashMap::new();
+
+        input.iter().for_each(|v| {
+            let focal_len: usize;
+            let box_num: usize;
+            let label: &str;
+            if v.contains("=") {
+                let t = v.split("=").collect::<Vec<&str>>();
+                label = t[0];
+                box_num = hash_alg(label);
+                focal_len = t[1].parse().expect("parse focal len");
+                boxes
+                    .entry(box_num)
+                    .and_modify(|lenses| {
+                        if let Some(idx) =
+                            lenses.iter().position(|lens| lens.0.eq(&label.to_string()))
+                        {
+                            lenses.remove(idx);
+                            lenses.insert(idx, (label.to_string(), focal_len));
+                        } else {
+                            lenses.push((label.to_string(), focal_len));
+                        }
+                    })
+                    .or_insert(vec![(label.to_string(), focal_len)]);
+            } else {
+                let t = v.trim_end_matches('-');
+                label = t;
+                box_num = hash_alg(label);
+                boxes.entry(box_num).and_modify(|lenses| {
+                    if let Some(idx) = lenses.iter().position(|lens| lens.0.eq(&label.to_string()))
+                    {
+                        lenses.remove(idx);
+                    }
+                });
+            }
+        });
+
+        let mut answer: usize = 0;
+        for (box_num, lenses) in boxes.iter() {
+            for (i, (_, focal_len)) in lenses.iter().enumerate() {
+                answer += (box_num + 1) * (i + 1) * (*focal_len);
+            }
+        }
+
+        answer
+    }
+}
+
+pub fn hash_alg(s: &str) -> usize {
+    let mut x = 0usize;
+    for val in s.chars().map(|c| c as usize) {
+        x = ((x + val) * 17) % 256;
+    }
+    x
+}
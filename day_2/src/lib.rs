@@ -0,0 +1,111 @@
+use common::{parse, ParseError, Solution};
+
+const MAX_RED: u32 = 12;
+const MAX_GREEN: u32 = 13;
+const MAX_BLUE: u32 = 14;
+
+#[derive(Debug)]
+pub struct Game {
+    id: u32,
+    r: Vec<u32>,
+    g: Vec<u32>,
+    b: Vec<u32>,
+}
+
+impl Game {
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    // whether every round could be drawn from a bag holding the given cubes
+    pub fn is_possible(&self, red: u32, green: u32, blue: u32) -> bool {
+        self.r.iter().all(|n| *n <= red)
+            && self.g.iter().all(|n| *n <= green)
+            && self.b.iter().all(|n| *n <= blue)
+    }
+
+    // product of the fewest cubes of each color that make the game possible
+    pub fn power(&self) -> u32 {
+        self.r.iter().max().unwrap_or(&0)
+            * self.g.iter().max().unwrap_or(&0)
+            * self.b.iter().max().unwrap_or(&0)
+    }
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Game>;
+    type Output = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| parse_line(i, line))
+            .collect()
+    }
+
+    // sum the ids of the games that could be played with the bag's cubes
+    fn part1(input: &Self::Input) -> Self::Output {
+        input
+            .iter()
+            .filter(|game| game.is_possible(MAX_RED, MAX_GREEN, MAX_BLUE))
+            .map(Game::id)
+            .sum()
+    }
+
+    fn part2(input: &Self::Input) -> Self::Output {
+        input.iter().map(Game::power).sum()
+    }
+}
+
+// parses "Game <id>: <count> <color>, ...; ..." where `line_idx` is only used to report errors
+pub fn parse_line(line_idx: usize, line: &str) -> Result<Game, ParseError> {
+    let mut game = Game {
+        id: 0u32,
+        r: vec![],
+        g: vec![],
+        b: vec![],
+    };
+
+    let Some((header, rounds)) = line.split_once(':') else {
+        return Err(ParseError::in_line(line_idx, line, line, "missing ':' in"));
+    };
+
+    // get game id
+    let Some(id) = header.strip_prefix("Game ") else {
+        return Err(ParseError::in_line(
+            line_idx,
+            line,
+            header,
+            "expected 'Game <id>', found",
+        ));
+    };
+    game.id = parse::number(line_idx, line, id)?;
+
+    // get cube counts
+    for round in rounds.split(';') {
+        // get the colors in each round
+        for color in round.split(',').map(str::trim) {
+            // separate num and color
+            let Some((num, name)) = color.split_once(' ') else {
+                return Err(ParseError::in_line(
+                    line_idx,
+                    line,
+                    color,
+                    "expected '<count> <color>', found",
+                ));
+            };
+            let num = parse::number::<u32>(line_idx, line, num)?;
+            match name {
+                "red" => game.r.push(num),
+                "green" => game.g.push(num),
+                "blue" => game.b.push(num),
+                _ => return Err(ParseError::in_line(line_idx, line, name, "unknown color")),
+            }
+        }
+    }
+
+    return Ok(game);
+}
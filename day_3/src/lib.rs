@@ -0,0 +1,95 @@
+use common::{parse, ParseError, Solution};
+use grid::{Grid, Point};
+use std::collections::{HashMap, HashSet};
+
+const RADIX: u32 = 10;
+
+pub struct Schematic {
+    grid: Grid<char>,
+    // Vec<(start, length, value)>
+    numbers: Vec<(Point, usize, u32)>,
+}
+
+impl Schematic {
+    fn is_symbol(c: char) -> bool {
+        !c.is_digit(RADIX) && c != '.'
+    }
+
+    // every point touching a number, diagonals included
+    fn number_area(&self, start: Point, len: usize) -> HashSet<Point> {
+        (start.x..(start.x + len))
+            .flat_map(|x| self.grid.neighbors8(Point::new(x, start.y)))
+            .collect()
+    }
+
+    // every number touching any symbol
+    pub fn part_numbers(&self) -> Vec<u32> {
+        self.numbers
+            .iter()
+            .filter(|(start, len, _)| {
+                self.number_area(*start, *len)
+                    .iter()
+                    .any(|p| Schematic::is_symbol(self.grid[*p]))
+            })
+            .map(|(_, _, val)| *val)
+            .collect()
+    }
+
+    // the product of the two numbers touching each gear, for gears touching exactly two
+    pub fn gear_ratios(&self) -> Vec<u32> {
+        let mut gear_hits: HashMap<Point, Vec<u32>> = HashMap::new();
+
+        // add the value of each number to every gear adjacent to it
+        for (start, len, val) in self.numbers.iter() {
+            self.number_area(*start, *len)
+                .into_iter()
+                .filter(|p| self.grid[*p] == '*')
+                .for_each(|gear| gear_hits.entry(gear).or_default().push(*val));
+        }
+
+        gear_hits
+            .values()
+            .filter_map(|ratios| match ratios[..] {
+                [r1, r2] => Some(r1 * r2),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Schematic;
+    type Output = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let grid = Grid::parse(input, |c| (!c.is_whitespace()).then_some(c))?;
+        let mut numbers: Vec<(Point, usize, u32)> = vec![];
+
+        // runs of digits in each row are numbers
+        for ((y, line), row) in input.lines().enumerate().zip(grid.rows()) {
+            let mut x = 0;
+            while x < row.len() {
+                let len = row[x..].iter().take_while(|c| c.is_digit(RADIX)).count();
+                if len > 0 {
+                    // digits are a byte each, whatever comes before them
+                    let start = line.char_indices().nth(x).map_or(0, |(b, _)| b);
+                    let val = parse::number(y, line, &line[start..(start + len)])?;
+                    numbers.push((Point::new(x, y), len, val));
+                }
+                x += len.max(1);
+            }
+        }
+
+        Ok(Schematic { grid, numbers })
+    }
+
+    fn part1(input: &Self::Input) -> Self::Output {
+        input.part_numbers().iter().sum()
+    }
+
+    fn part2(input: &Self::Input) -> Self::Output {
+        input.gear_ratios().iter().sum()
+    }
+}
@@ -0,0 +1,146 @@
+use common::{parse, ParseError, Solution};
+use std::collections::{HashMap, HashSet};
+
+struct CardMap {
+    card_counts: HashMap<u32, u32>,
+}
+impl CardMap {
+    fn add_or_insert(&mut self, key: u32, val: u32) {
+        match self.card_counts.get_mut(&key) {
+            Some(stored) => *stored += val,
+            None => {
+                let _ = self.card_counts.insert(key, val);
+            }
+        }
+    }
+
+    fn get(&mut self, key: u32) -> u32 {
+        match self.card_counts.get(&key) {
+            Some(val) => *val,
+            None => {
+                let _ = self.card_counts.insert(key, 0);
+                0
+            }
+        }
+    }
+}
+
+pub struct Card {
+    num: u32,
+    winning_nums: HashSet<u32>,
+    candidates: Vec<u32>,
+}
+
+impl Card {
+    pub fn num(&self) -> u32 {
+        self.num
+    }
+
+    // count of scratched off nums that are also winning nums
+    pub fn matches(&self) -> u32 {
+        self.candidates
+            .iter()
+            .filter(|num| self.winning_nums.contains(num))
+            .count() as u32
+    }
+
+    // worth 1 point for the first match, doubled for every match after that
+    pub fn points(&self) -> u32 {
+        match self.matches() {
+            0 => 0,
+            matches => 1u32
+                .checked_shl(matches - 1)
+                .expect("parse caps the matches on a card"),
+        }
+    }
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<Card>;
+    type Output = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                let Some((header, numbers)) = line.split_once(':') else {
+                    return Err(ParseError::in_line(i, line, line, "missing ':' in"));
+                };
+
+                // "Card   #" to #
+                let num: u32 = match header.strip_prefix("Card") {
+                    Some(num) => parse::number(i, line, num.trim())?,
+                    None => {
+                        return Err(ParseError::in_line(
+                            i,
+                            line,
+                            header,
+                            "expected 'Card <num>', found",
+                        ))
+                    }
+                };
+
+                let Some((winners, candidates)) = numbers.split_once('|') else {
+                    return Err(ParseError::in_line(i, line, numbers, "missing '|' in"));
+                };
+
+                // read winning nums
+                let winning_nums: HashSet<u32> = winners
+                    .split_whitespace()
+                    .map(|num| parse::number(i, line, num))
+                    .collect::<Result<_, _>>()?;
+
+                // read scratch off nums
+                let candidates: Vec<u32> = candidates
+                    .split_whitespace()
+                    .map(|num| parse::number(i, line, num))
+                    .collect::<Result<_, _>>()?;
+
+                let card = Card {
+                    num,
+                    winning_nums,
+                    candidates,
+                };
+                // the points double with every match, so past this they don't fit in a u32
+                if card.matches() > u32::BITS {
+                    return Err(ParseError::in_line(
+                        i,
+                        line,
+                        numbers,
+                        format!("more than {} matches in", u32::BITS),
+                    ));
+                }
+                Ok(card)
+            })
+            .collect()
+    }
+
+    fn part1(input: &Self::Input) -> Self::Output {
+        input.iter().map(Card::points).sum()
+    }
+
+    fn part2(input: &Self::Input) -> Self::Output {
+        let mut card_map = CardMap {
+            card_counts: HashMap::new(),
+        };
+
+        input
+            .iter()
+            .map(|card| {
+                // add the original card
+                card_map.add_or_insert(card.num, 1);
+
+                let curr_card_count = card_map.get(card.num);
+
+                // add the count of the current card to the next <matches> cards counts
+                ((card.num + 1)..=(card.num + card.matches())).for_each(|future_card_num| {
+                    card_map.add_or_insert(future_card_num, curr_card_count)
+                });
+                return curr_card_count;
+            })
+            .sum()
+    }
+}
@@ -0,0 +1,373 @@
+use common::{parse, ParseError, Solution};
+use std::{cell::OnceCell, fmt::Display, ops::Range};
+
+// a piecewise-linear mapping, values outside every mapping pass through unchanged
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Transformation {
+    // (lower_bound, upper_bound exclusive, transformer), sorted and never overlapping
+    mappings: Vec<(i64, i64, i64)>,
+}
+impl Transformation {
+    pub fn new() -> Self {
+        Self { mappings: vec![] }
+    }
+
+    // panics if the mapping overlaps one that was already added, see `overlaps`
+    pub fn add_mapping(&mut self, dest_start: i64, source_start: i64, range_len: i64) {
+        if range_len <= 0 {
+            return;
+        }
+        assert!(
+            !self.overlaps(source_start, range_len),
+            "mapping for {}..{} overlaps another",
+            source_start,
+            source_start + range_len
+        );
+        let idx = self
+            .mappings
+            .partition_point(|(lower_bound, _, _)| *lower_bound < source_start);
+        self.mappings.insert(
+            idx,
+            (
+                source_start,
+                source_start + range_len,
+                dest_start - source_start,
+            ),
+        );
+    }
+
+    // whether any source value in the range is already mapped
+    pub fn overlaps(&self, source_start: i64, range_len: i64) -> bool {
+        let source_end = source_start + range_len;
+        self.mappings.iter().any(|(lower_bound, upper_bound, _)| {
+            source_start < *upper_bound && *lower_bound < source_end
+        })
+    }
+
+    // the mappings as (source range, transformer) pairs
+    pub fn mappings(&self) -> impl Iterator<Item = (Range<i64>, i64)> + '_ {
+        self.mappings
+            .iter()
+            .map(|(lower_bound, upper_bound, transformer)| {
+                (*lower_bound..*upper_bound, *transformer)
+            })
+    }
+
+    // binary search for the only mapping that could hold the number
+    pub fn transform(&self, source_num: i64) -> i64 {
+        let idx = self
+            .mappings
+            .partition_point(|(lower_bound, _, _)| *lower_bound <= source_num);
+        match idx.checked_sub(1).map(|idx| self.mappings[idx]) {
+            // source_num IS is the range of this mapping
+            Some((_, upper_bound, transformer)) if source_num < upper_bound => {
+                source_num + transformer
+            }
+            _ => source_num,
+        }
+    }
+
+    // `range` cut at every mapping bound, in order, each piece paired with its transformer
+    fn split(&self, range: Range<i64>) -> Vec<(Range<i64>, i64)> {
+        if range.is_empty() {
+            return vec![];
+        }
+        let mut pieces: Vec<(Range<i64>, i64)> = vec![];
+        let mut cursor = range.start;
+
+        let first = self
+            .mappings
+            .partition_point(|(_, upper_bound, _)| *upper_bound <= range.start);
+        for (lower_bound, upper_bound, transformer) in self.mappings[first..].iter() {
+            if *lower_bound >= range.end {
+                break;
+            }
+            if cursor < *lower_bound {
+                pieces.push((cursor..*lower_bound, 0));
+            }
+            let end = range.end.min(*upper_bound);
+            pieces.push((cursor.max(*lower_bound)..end, *transformer));
+            cursor = end;
+        }
+        if cursor < range.end {
+            pieces.push((cursor..range.end, 0));
+        }
+
+        pieces
+    }
+
+    // maps every value in `range`, splitting it wherever it crosses a mapping's bounds;
+    // the parts outside every mapping pass through unchanged
+    pub fn transform_range(&self, range: Range<i64>) -> Vec<Range<i64>> {
+        self.split(range)
+            .into_iter()
+            .map(|(r, transformer)| (r.start + transformer)..(r.end + transformer))
+            .collect()
+    }
+
+    // a single transformation doing `self` and then `next`
+    pub fn compose(&self, next: &Transformation) -> Transformation {
+        // both are the identity outside of every bound they have
+        let bounds = self.mappings.iter().chain(next.mappings.iter());
+        let lower = bounds.clone().map(|(lower_bound, _, _)| *lower_bound).min();
+        let upper = bounds.map(|(_, upper_bound, _)| *upper_bound).max();
+        let (Some(lower), Some(upper)) = (lower, upper) else {
+            return Transformation::new();
+        };
+
+        let mut composed = Transformation::new();
+        for (r, transformer) in self.split(lower..upper) {
+            let shifted = (r.start + transformer)..(r.end + transformer);
+            for (r_next, transformer_next) in next.split(shifted) {
+                let total = transformer + transformer_next;
+                if total == 0 {
+                    continue;
+                }
+                let lower_bound = r_next.start - transformer;
+                let upper_bound = r_next.end - transformer;
+
+                // pieces come in order, so neighbors with the same shift can be merged
+                match composed.mappings.last_mut() {
+                    Some((_, prev_upper, prev_transformer))
+                        if *prev_upper == lower_bound && *prev_transformer == total =>
+                    {
+                        *prev_upper = upper_bound
+                    }
+                    _ => composed.mappings.push((lower_bound, upper_bound, total)),
+                }
+            }
+        }
+        composed
+    }
+
+    // the transformation mapping every result back to its source, if no two sources share
+    // a result
+    pub fn invert(&self) -> Option<Transformation> {
+        let mut inverse = Transformation::new();
+        for (lower_bound, upper_bound, transformer) in self.mappings.iter() {
+            let dest_start = lower_bound + transformer;
+            if inverse.overlaps(dest_start, upper_bound - lower_bound) {
+                return None;
+            }
+            inverse.add_mapping(*lower_bound, dest_start, upper_bound - lower_bound);
+        }
+
+        // values outside every mapping keep theirs, so the mapped results have to cover
+        // exactly the mapped sources
+        let covers = |tr: &Transformation| {
+            tr.mappings.iter().fold(
+                vec![],
+                |mut covered: Vec<Range<i64>>, (lower_bound, upper_bound, _)| {
+                    match covered.last_mut() {
+                        Some(prev) if prev.end == *lower_bound => prev.end = *upper_bound,
+                        _ => covered.push(*lower_bound..*upper_bound),
+                    }
+                    covered
+                },
+            )
+        };
+        match covers(self) == covers(&inverse) {
+            true => Some(inverse),
+            false => None,
+        }
+    }
+}
+
+// one mapping per line, "[source start, source end) -> [dest start, dest end)"
+impl Display for Transformation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, (lower_bound, upper_bound, transformer)) in self.mappings.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(
+                f,
+                "[{}, {}) -> [{}, {}) ({:+})",
+                lower_bound,
+                upper_bound,
+                lower_bound + transformer,
+                upper_bound + transformer,
+                transformer
+            )?;
+        }
+        Ok(())
+    }
+}
+
+pub struct Almanac {
+    seeds: Vec<i64>,
+    transformations: Vec<Transformation>,
+    // the pipeline run backwards, worked out the first time a seed is looked up
+    inverse: OnceCell<Option<Transformation>>,
+}
+
+impl Almanac {
+    pub fn seeds(&self) -> &[i64] {
+        &self.seeds
+    }
+
+    pub fn transformations(&self) -> &[Transformation] {
+        &self.transformations
+    }
+
+    // run the seed through every transformation to find its location
+    pub fn locate(&self, seed: i64) -> i64 {
+        self.transformations
+            .iter()
+            .fold(seed, |s, tr| tr.transform(s))
+    }
+
+    // push a whole range of seeds through every transformation, the locations come back as
+    // a set of ranges
+    pub fn locate_range(&self, seeds: Range<i64>) -> Vec<Range<i64>> {
+        self.transformations.iter().fold(vec![seeds], |ranges, tr| {
+            ranges
+                .into_iter()
+                .flat_map(|r| tr.transform_range(r))
+                .collect()
+        })
+    }
+
+    // every transformation combined into one, seeds in and locations out
+    pub fn pipeline(&self) -> Transformation {
+        self.transformations
+            .iter()
+            .fold(Transformation::new(), |pipeline, tr| pipeline.compose(tr))
+    }
+
+    // the seed planted at `location`, if the maps send every seed somewhere different; each
+    // lookup after the first is a single search
+    pub fn find_seed(&self, location: i64) -> Option<i64> {
+        let inverse = self.inverse.get_or_init(|| self.pipeline().invert());
+        Some(inverse.as_ref()?.transform(location))
+    }
+}
+
+// the transformations are combined first so there's only one lookup per seed range
+fn process(almanac: &Almanac) -> i64 {
+    let pipeline = almanac.pipeline();
+
+    almanac
+        .seeds
+        .chunks(2)
+        .flat_map(|seed_range| {
+            pipeline.transform_range(seed_range[0]..(seed_range[0] + seed_range[1]))
+        })
+        .map(|location_range| location_range.start)
+        .min()
+        .expect("at least one seed range")
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Almanac;
+    type Output = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut lines = input.lines().enumerate();
+        let seeds: Vec<i64> = match lines.next() {
+            Some((i, line)) => match line.strip_prefix("seeds: ") {
+                Some(nums) => nums
+                    .split_whitespace()
+                    .map(|n| parse::number(i, line, n))
+                    .collect::<Result<_, _>>()?,
+                None => {
+                    return Err(ParseError::in_line(
+                        i,
+                        line,
+                        line,
+                        "expected 'seeds: ', found",
+                    ))
+                }
+            },
+            None => return Err(ParseError::new(1, 1, "", "missing seeds line")),
+        };
+        if seeds.is_empty() {
+            return Err(ParseError::new(
+                1,
+                1,
+                input.lines().next().unwrap_or_default(),
+                "no seeds in",
+            ));
+        }
+        // part 2 reads the seeds as (start, length) pairs
+        if !seeds.len().is_multiple_of(2) {
+            return Err(ParseError::new(
+                1,
+                1,
+                input.lines().next().unwrap_or_default(),
+                "odd number of seeds in",
+            ));
+        }
+        if seeds.chunks(2).any(|pair| pair[1] <= 0) {
+            return Err(ParseError::new(
+                1,
+                1,
+                input.lines().next().unwrap_or_default(),
+                "empty seed range in",
+            ));
+        }
+
+        let mut transformations: Vec<Transformation> = vec![];
+
+        for (i, line) in lines {
+            if line.is_empty() {
+                continue;
+            } else if line.ends_with("map:") {
+                transformations.push(Transformation::new());
+            } else {
+                let values: Vec<i64> = line
+                    .split_whitespace()
+                    .map(|n| parse::number(i, line, n))
+                    .collect::<Result<_, _>>()?;
+                let [dest_start, source_start, range_len] = values[..] else {
+                    return Err(ParseError::in_line(
+                        i,
+                        line,
+                        line,
+                        "expected '<dest> <source> <length>', found",
+                    ));
+                };
+                let Some(transformation) = transformations.last_mut() else {
+                    return Err(ParseError::in_line(
+                        i,
+                        line,
+                        line,
+                        "mapping before any map header",
+                    ));
+                };
+                if range_len < 0 || transformation.overlaps(source_start, range_len) {
+                    return Err(ParseError::in_line(
+                        i,
+                        line,
+                        line,
+                        "mapping overlaps another or has a negative length",
+                    ));
+                }
+                transformation.add_mapping(dest_start, source_start, range_len);
+            }
+        }
+
+        Ok(Almanac {
+            seeds,
+            transformations,
+            inverse: OnceCell::new(),
+        })
+    }
+
+    // every seed value is a seed of its own
+    fn part1(input: &Self::Input) -> Self::Output {
+        input
+            .seeds
+            .iter()
+            .map(|seed| input.locate(*seed))
+            .min()
+            .expect("at least one seed")
+    }
+
+    // seed values come in (start, length) pairs
+    fn part2(input: &Self::Input) -> Self::Output {
+        process(input)
+    }
+}
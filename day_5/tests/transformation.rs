@@ -0,0 +1,106 @@
+use common::Solution;
+use day_5::{Day5, Transformation};
+
+#[test]
+fn transform_shifts_values_inside_a_mapping() {
+    let mut tr = Transformation::new();
+    tr.add_mapping(50, 98, 2);
+    tr.add_mapping(52, 50, 48);
+
+    assert_eq!(tr.transform(79), 81);
+    assert_eq!(tr.transform(98), 50);
+    assert_eq!(tr.transform(99), 51);
+}
+
+#[test]
+fn transform_passes_through_unmapped_values() {
+    let mut tr = Transformation::new();
+    tr.add_mapping(50, 98, 2);
+
+    assert_eq!(tr.transform(10), 10);
+    assert_eq!(tr.transform(100), 100);
+}
+
+#[test]
+fn transform_range_splits_across_mapping_bounds() {
+    let mut tr = Transformation::new();
+    tr.add_mapping(50, 98, 2);
+    tr.add_mapping(52, 50, 48);
+
+    let mut ranges = tr.transform_range(45..100);
+    ranges.sort_by_key(|r| r.start);
+    assert_eq!(ranges, vec![45..50, 50..52, 52..100]);
+
+    assert_eq!(tr.transform_range(10..20), vec![10..20]);
+}
+
+#[test]
+fn transform_range_of_an_empty_range_is_empty() {
+    let mut tr = Transformation::new();
+    tr.add_mapping(50, 70, 20);
+
+    assert!(tr.transform_range(79..79).is_empty());
+    assert!(tr.transform_range(10..10).is_empty());
+}
+
+#[test]
+fn almanac_locates_seeds_through_every_map() {
+    let almanac = Day5::parse(include_str!("../resources/sample.txt")).expect("parse sample");
+
+    assert_eq!(almanac.seeds(), &[79, 14, 55, 13]);
+    assert_eq!(almanac.transformations().len(), 7);
+    assert_eq!(almanac.locate(79), 82);
+    assert_eq!(almanac.locate(13), 35);
+}
+
+#[test]
+fn almanac_locates_whole_seed_ranges() {
+    let almanac = Day5::parse(include_str!("../resources/sample.txt")).expect("parse sample");
+
+    let lowest = almanac.locate_range(82..83);
+    assert_eq!(lowest, vec![46..47]);
+
+    let locations = almanac.locate_range(79..93);
+    assert_eq!(locations.iter().map(|r| r.end - r.start).sum::<i64>(), 14);
+    assert_eq!(locations.iter().map(|r| r.start).min(), Some(46));
+}
+
+#[test]
+fn composed_transformation_matches_applying_each_in_turn() {
+    let almanac = Day5::parse(include_str!("../resources/sample.txt")).expect("parse sample");
+    let pipeline = almanac.pipeline();
+
+    for seed in 0..120 {
+        assert_eq!(
+            pipeline.transform(seed),
+            almanac.locate(seed),
+            "seed {}",
+            seed
+        );
+    }
+}
+
+#[test]
+fn inverse_maps_locations_back_to_seeds() {
+    let almanac = Day5::parse(include_str!("../resources/sample.txt")).expect("parse sample");
+
+    assert_eq!(almanac.find_seed(82), Some(79));
+    assert_eq!(almanac.find_seed(46), Some(82));
+
+    // 10 and 50 both end up at 50
+    let mut tr = Transformation::new();
+    tr.add_mapping(50, 10, 1);
+    assert_eq!(tr.invert(), None);
+}
+
+#[test]
+fn prints_one_mapping_per_line() {
+    let mut tr = Transformation::new();
+    tr.add_mapping(52, 50, 48);
+    tr.add_mapping(50, 98, 2);
+
+    assert_eq!(
+        tr.to_string(),
+        "[50, 98) -> [52, 100) (+2)\n[98, 100) -> [50, 52) (-48)"
+    );
+}
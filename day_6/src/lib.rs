@@ -0,0 +1,113 @@
+use common::{parse, ParseError, Solution};
+
+pub struct Races {
+    times: Vec<i64>,
+    distances: Vec<i64>,
+    // the spaces between numbers are just bad kerning, it's really one long race
+    time: i64,
+    distance: i64,
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Races;
+    type Output = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let (times, time) = parse_nums_from_line(input, 0, "Time:")?;
+        let (distances, distance) = parse_nums_from_line(input, 1, "Distance:")?;
+        let races = Races {
+            times,
+            distances,
+            time,
+            distance,
+        };
+        if races.times.len() != races.distances.len() {
+            let line = input.lines().nth(1).unwrap_or_default();
+            return Err(ParseError::in_line(
+                1,
+                line,
+                line,
+                "distance count doesn't match time count in",
+            ));
+        }
+        Ok(races)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Output {
+        product_of_ways(&input.times, &input.distances)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Output {
+        ways_to_win(input.time, input.distance)
+    }
+}
+
+fn product_of_ways(times: &[i64], distances: &[i64]) -> i64 {
+    times
+        .iter()
+        .zip(distances.iter())
+        .map(|(time, record_dist)| ways_to_win(*time, *record_dist))
+        .product()
+}
+
+// count of button hold times that beat the record distance in a race of the given time
+pub fn ways_to_win(time: i64, record_dist: i64) -> i64 {
+    // you can set up the values as "dist = held_time * (race_time - held_time)", or:
+    // "held_time**2 - race_time*held_time + dist = 0", and then calc roots of that parabola
+    let (l, h) = get_roots(1f64, -(time as f64), record_dist as f64);
+    let (low, high) = (l.ceil() as i64, h.floor() as i64);
+
+    let mut p = high - low + 1;
+    if ((h as i64 * time) - (h.powi(2) as i64)) == record_dist {
+        p -= 2;
+    }
+
+    p
+}
+
+// the numbers following `label` on a line like "Time:      7  15   30", and the one number
+// they make with the spaces taken out
+fn parse_nums_from_line(
+    input: &str,
+    line_idx: usize,
+    label: &str,
+) -> Result<(Vec<i64>, i64), ParseError> {
+    let Some(line) = input.lines().nth(line_idx) else {
+        return Err(ParseError::new(line_idx + 1, 1, label, "missing line"));
+    };
+    let Some(nums) = line.strip_prefix(label) else {
+        return Err(ParseError::in_line(
+            line_idx,
+            line,
+            line,
+            format!("expected '{}', found", label),
+        ));
+    };
+    let tokens: Vec<&str> = nums.split_whitespace().collect();
+    if tokens.is_empty() {
+        return Err(ParseError::in_line(line_idx, line, line, "no numbers in"));
+    }
+
+    let values = tokens
+        .iter()
+        .map(|n| parse::number(line_idx, line, n))
+        .collect::<Result<_, _>>()?;
+    let unkerned = tokens.concat().parse::<i64>().map_err(|_| {
+        ParseError::in_line(
+            line_idx,
+            line,
+            nums.trim(),
+            "expected one number without the spaces, found",
+        )
+    })?;
+
+    Ok((values, unkerned))
+}
+
+fn get_roots(a: f64, b: f64, c: f64) -> (f64, f64) {
+    let term1 = (b.powi(2) - (4f64 * a * c)).sqrt();
+    let term2 = 2f64 * a;
+    return (((-b) - term1) / term2, ((-b) + term1) / term2);
+}
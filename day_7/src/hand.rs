@@ -1,26 +1,36 @@
-use crate::rank::{Rank, JOKER};
+use crate::rank::{InvalidRank, Rank, JOKER};
 use itertools::Itertools;
-use std::fmt::Display;
+use std::{error::Error, fmt::Display, str::FromStr};
 
 const HAND_SIZE: usize = 5;
 
-#[derive(PartialEq, Eq)]
-pub struct Hand([Rank; HAND_SIZE]);
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Hand {
+    cards: [Rank; HAND_SIZE],
+    jokers_wild: bool,
+}
 
 impl Hand {
+    pub fn with_jokers_wild(&self, jokers_wild: bool) -> Self {
+        Self {
+            cards: self.cards,
+            jokers_wild,
+        }
+    }
+
     pub fn get_value(&self) -> u32 {
         let mut joker_count: u32 = 0;
 
         // count of each card rank
         let mut freq_counts: Vec<u32> = self
-            .0
+            .cards
             .iter()
             .sorted()
             .group_by(|r| *r)
             .into_iter()
             .filter_map(|(key, group)| {
                 // extract and remove the joker count from this list
-                if (*key).eq(&JOKER) {
+                if self.jokers_wild && *key == JOKER {
                     joker_count = group.count() as u32;
                     return None;
                 }
@@ -30,7 +40,7 @@ impl Hand {
             .collect_vec();
 
         // add the joker count to the highest count of non-joker card ranks is
-        if self.0.contains(&JOKER) {
+        if self.jokers_wild && self.cards.contains(&JOKER) {
             if let Some(count) = freq_counts.last_mut() {
                 *count += joker_count;
             } else {
@@ -56,34 +66,44 @@ impl Hand {
     }
 }
 
-impl From<String> for Hand {
-    fn from(value: String) -> Self {
-        assert!(value.len() == HAND_SIZE);
-        Self(
-            value
-                .chars()
-                .map(Rank::from)
-                .collect_vec()
-                .try_into()
-                .expect("build array"),
-        )
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum HandError {
+    // the number of cards found
+    Size(usize),
+    // `idx` is the byte offset of the card in the hand string
+    Card { idx: usize, rank: InvalidRank },
+}
+
+impl Display for HandError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HandError::Size(size) => write!(f, "expected {} cards, found {}", HAND_SIZE, size),
+            HandError::Card { idx, rank } => write!(f, "card {}: {}", idx + 1, rank),
+        }
+    }
+}
+
+impl Error for HandError {}
+
+impl FromStr for Hand {
+    type Err = HandError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let cards: Vec<Rank> = s
+            .char_indices()
+            .map(|(idx, c)| Rank::try_from(c).map_err(|rank| HandError::Card { idx, rank }))
+            .collect::<Result<_, _>>()?;
+        let size = cards.len();
+        Ok(Self {
+            cards: cards.try_into().map_err(|_| HandError::Size(size))?,
+            jokers_wild: false,
+        })
     }
 }
 
 impl PartialOrd for Hand {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        match self.get_value().partial_cmp(&other.get_value()) {
-            Some(std::cmp::Ordering::Equal) => {
-                for (r1, r2) in self.0.iter().zip(other.0.iter()) {
-                    match r1.cmp(r2) {
-                        std::cmp::Ordering::Equal => continue,
-                        ordering => return Some(ordering),
-                    }
-                }
-                Some(std::cmp::Ordering::Equal)
-            }
-            otherwise => otherwise,
-        }
+        Some(self.cmp(other))
     }
 }
 
@@ -91,8 +111,8 @@ impl Ord for Hand {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
         match self.get_value().cmp(&other.get_value()) {
             std::cmp::Ordering::Equal => {
-                for (r1, r2) in self.0.iter().zip(other.0.iter()) {
-                    match r1.cmp(r2) {
+                for (r1, r2) in self.cards.iter().zip(other.cards.iter()) {
+                    match r1.value(self.jokers_wild).cmp(&r2.value(self.jokers_wild)) {
                         std::cmp::Ordering::Equal => continue,
                         ordering => return ordering,
                     }
@@ -106,6 +126,6 @@ impl Ord for Hand {
 
 impl Display for Hand {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.0.iter().map(|r| r.0).join(""))
+        write!(f, "{}", self.cards.iter().map(|r| r.0).join(""))
     }
 }
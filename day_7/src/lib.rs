@@ -0,0 +1,57 @@
+pub mod hand;
+pub mod rank;
+use common::{parse, ParseError, Solution};
+use hand::{Hand, HandError};
+use itertools::Itertools;
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<(Hand, u64)>;
+    type Output = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                let Some((hand_str, bid_str)) = line.split_whitespace().collect_tuple() else {
+                    return Err(ParseError::in_line(
+                        i,
+                        line,
+                        line,
+                        "expected '<hand> <bid>', found",
+                    ));
+                };
+                let hand = hand_str.parse::<Hand>().map_err(|e| match e {
+                    HandError::Size(_) => ParseError::in_line(i, line, hand_str, e.to_string()),
+                    HandError::Card { idx, rank } => ParseError::in_line(
+                        i,
+                        line,
+                        &hand_str[idx..idx + rank.0.len_utf8()],
+                        "unknown card",
+                    ),
+                })?;
+                Ok((hand, parse::number(i, line, bid_str)?))
+            })
+            .collect()
+    }
+
+    fn part1(input: &Self::Input) -> Self::Output {
+        total_winnings(input, false)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Output {
+        total_winnings(input, true)
+    }
+}
+
+fn total_winnings(hands: &[(Hand, u64)], jokers_wild: bool) -> u64 {
+    hands
+        .iter()
+        .map(|(hand, bid)| (hand.with_jokers_wild(jokers_wild), *bid))
+        .sorted()
+        .zip(1..)
+        .map(|(val, i)| val.1 * (i as u64))
+        .sum::<u64>()
+}
@@ -1,32 +1,9 @@
-mod hand;
-mod rank;
-use hand::Hand;
-use itertools::Itertools;
-use std::io::{stdin, BufRead, BufReader};
+use day_7::Day7;
 
 fn main() {
-    let lines = BufReader::new(stdin().lock()).lines();
-    let hands: Vec<(Hand, u64)> = lines
-        .map(|record| match record {
-            Ok(line) => {
-                let (hand_str, bid_str): (&str, &str) =
-                    line.split(" ").collect_tuple().expect("build tuple");
-                (
-                    Hand::from(hand_str.to_string()),
-                    bid_str.parse::<u64>().expect("parsed u64"),
-                )
-            }
-            _ => panic!(),
-        })
-        .collect_vec();
+    let input = common::input::or_exit(common::input::load_from_args(
+        &common::default_input_path!(),
+    ));
 
-    println!(
-        "total winnings: {}",
-        hands
-            .iter()
-            .sorted()
-            .zip(1..)
-            .map(|(val, i)| { val.1 * (i as u64) })
-            .sum::<u64>()
-    );
+    common::print_answers::<Day7>(&input);
 }
@@ -1,39 +1,64 @@
 use once_cell::sync::Lazy;
 use std::collections::HashMap;
-use std::fmt::Display;
+use std::{error::Error, fmt::Display};
 
 static RANK_STR: &str = "J23456789TQKA";
 static RANK_MAP: Lazy<HashMap<char, u32>> = Lazy::new(|| {
     HashMap::<char, u32>::from_iter(RANK_STR.char_indices().map(|(i, c)| (c, i as u32)))
 });
+static JACK_RANK_STR: &str = "23456789TJQKA";
+static JACK_RANK_MAP: Lazy<HashMap<char, u32>> = Lazy::new(|| {
+    HashMap::<char, u32>::from_iter(JACK_RANK_STR.char_indices().map(|(i, c)| (c, i as u32)))
+});
 
-pub const JOKER: Lazy<Rank> = Lazy::new(|| Rank::from('J'));
+pub const JOKER: Rank = Rank('J');
 
 #[derive(PartialEq, Eq, Debug, Clone, Copy)]
 pub struct Rank(pub char);
 
-impl From<char> for Rank {
-    fn from(value: char) -> Self {
-        assert!(RANK_STR.contains(value));
-        Self(value)
+// a card that isn't one of "23456789TJQKA"
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub struct InvalidRank(pub char);
+
+impl Display for InvalidRank {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "'{}' isn't a card rank", self.0)
+    }
+}
+
+impl Error for InvalidRank {}
+
+impl TryFrom<char> for Rank {
+    type Error = InvalidRank;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match RANK_STR.contains(value) {
+            true => Ok(Self(value)),
+            false => Err(InvalidRank(value)),
+        }
+    }
+}
+
+impl Rank {
+    // a 'J' is the weakest card when it's a joker, otherwise it's a jack
+    pub fn value(&self, jokers_wild: bool) -> u32 {
+        let rank_map = match jokers_wild {
+            true => &RANK_MAP,
+            false => &JACK_RANK_MAP,
+        };
+        *rank_map.get(&self.0).expect("rank value")
     }
 }
 
 impl PartialOrd for Rank {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        RANK_MAP
-            .get(&self.0)
-            .expect("rank value")
-            .partial_cmp(&RANK_MAP.get(&other.0).expect("rank value"))
+        Some(self.cmp(other))
     }
 }
 
 impl Ord for Rank {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        RANK_MAP
-            .get(&self.0)
-            .expect("rank value")
-            .cmp(&RANK_MAP.get(&other.0).expect("rank value"))
+        self.value(true).cmp(&other.value(true))
     }
 }
 
@@ -0,0 +1,48 @@
+use day_7::{
+    hand::{Hand, HandError},
+    rank::InvalidRank,
+};
+
+fn hand(cards: &str) -> Hand {
+    cards.parse().expect("valid hand")
+}
+
+#[test]
+fn hand_values_without_jokers() {
+    assert_eq!(hand("AAAAA").get_value(), 7);
+    assert_eq!(hand("KK677").get_value(), 3);
+    assert_eq!(hand("KTJJT").get_value(), 3);
+    assert_eq!(hand("32T3K").get_value(), 2);
+    assert_eq!(hand("23456").get_value(), 1);
+}
+
+#[test]
+fn jokers_join_the_largest_group() {
+    assert_eq!(hand("KTJJT").with_jokers_wild(true).get_value(), 6);
+    assert_eq!(hand("QQQJA").with_jokers_wild(true).get_value(), 6);
+    assert_eq!(hand("JJJJJ").with_jokers_wild(true).get_value(), 7);
+}
+
+#[test]
+fn ties_break_on_first_differing_card() {
+    assert!(hand("KK677") > hand("KTJJT"));
+    assert!(hand("QQQJA") > hand("T55J5"));
+
+    // a joker is the weakest card, a jack sits between ten and queen
+    assert!(hand("JKKK2").with_jokers_wild(true) < hand("QQQQ2").with_jokers_wild(true));
+    assert!(hand("J2345") > hand("T2345"));
+    assert!(hand("J2345").with_jokers_wild(true) < hand("22345").with_jokers_wild(true));
+}
+
+#[test]
+fn rejects_malformed_hands() {
+    assert_eq!(
+        "KK6X7".parse::<Hand>(),
+        Err(HandError::Card {
+            idx: 3,
+            rank: InvalidRank('X')
+        })
+    );
+    assert_eq!("KK67".parse::<Hand>(), Err(HandError::Size(4)));
+    assert_eq!("KK6777".parse::<Hand>(), Err(HandError::Size(6)));
+}
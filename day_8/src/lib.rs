@@ -0,0 +1,117 @@
+use common::{ParseError, Solution};
+use num_integer::Integer;
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+};
+
+pub struct Network {
+    turns: String,
+    map: HashMap<String, (String, String)>,
+}
+
+impl Network {
+    // count the turns from the start node until the first node that satisfies `is_end`; none if
+    // a node along the way isn't in the map, or if a pass over the turns starts from a node an
+    // earlier pass started from, after which the same nodes just come round again
+    pub fn count_turns(&self, start: &str, is_end: impl Fn(&str) -> bool) -> Option<u64> {
+        let mut node: &str = start;
+        let mut count = 0;
+        let mut pass_starts: HashSet<&str> = HashSet::new();
+        for (idx, turn) in self.turns.chars().enumerate().cycle() {
+            if idx == 0 && !pass_starts.insert(node) {
+                return None;
+            }
+            let (left, right) = self.map.get(node)?;
+            node = match turn {
+                'L' => left,
+                _ => right,
+            };
+            count += 1;
+            if is_end(node) {
+                return Some(count);
+            }
+        }
+        None
+    }
+}
+
+// how many turns it takes, if the end can be reached at all
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Turns(pub Option<u64>);
+
+impl Display for Turns {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            Some(count) => write!(f, "{}", count),
+            None => write!(f, "unreachable"),
+        }
+    }
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Network;
+    type Output = Turns;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut lines = input.lines().enumerate();
+
+        let turns: String = match lines.next() {
+            Some((i, line)) => match line.char_indices().find(|(_, c)| !['L', 'R'].contains(c)) {
+                Some((col, c)) => {
+                    let turn = &line[col..col + c.len_utf8()];
+                    return Err(ParseError::in_line(
+                        i,
+                        line,
+                        turn,
+                        "expected 'L' or 'R', found",
+                    ));
+                }
+                None => line.to_string(),
+            },
+            None => return Err(ParseError::new(1, 1, "", "missing turns line")),
+        };
+        let mut map: HashMap<String, (String, String)> = HashMap::new();
+
+        // build map from lines like "AAA = (BBB, CCC)"
+        for (i, line) in lines.filter(|(_, line)| !line.is_empty()) {
+            let conns = line
+                .split_once(" = ")
+                .and_then(|(key, tuple_str)| {
+                    Some((key, tuple_str.strip_prefix('(')?.strip_suffix(')')?))
+                })
+                .and_then(|(key, tuple_str)| Some((key, tuple_str.split_once(", ")?)));
+            let Some((key, (left, right))) = conns else {
+                return Err(ParseError::in_line(
+                    i,
+                    line,
+                    line,
+                    "expected '<node> = (<left>, <right>)', found",
+                ));
+            };
+
+            map.insert(key.to_string(), (left.to_string(), right.to_string()));
+        }
+
+        Ok(Network { turns, map })
+    }
+
+    fn part1(input: &Self::Input) -> Self::Output {
+        Turns(input.count_turns("AAA", |node| node == "ZZZ"))
+    }
+
+    fn part2(input: &Self::Input) -> Self::Output {
+        // count the turns to the first z node for each node that ends with a
+        let first_hits: Option<Vec<u64>> = input
+            .map
+            .keys()
+            .filter(|key| key.ends_with('A'))
+            .map(|start| input.count_turns(start, |node| node.ends_with('Z')))
+            .collect();
+
+        // find the least common multiple of all the counts
+        Turns(first_hits.map(|hits| hits.iter().fold(1u64, |acc, i| acc.lcm(i))))
+    }
+}
@@ -0,0 +1,19 @@
+use common::Solution;
+use day_8::{Day8, Turns};
+
+#[test]
+fn missing_start_is_unreachable() {
+    let network = Day8::parse(include_str!("../resources/sample.txt")).expect("parse sample");
+    assert_eq!(Day8::part1(&network), Turns(None));
+    assert_eq!(Day8::part2(&network), Turns(Some(6)));
+}
+
+#[test]
+fn gives_up_once_the_turns_go_round_in_circles() {
+    let network = Day8::parse("LR\n\nAAA = (BBB, BBB)\nBBB = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)")
+        .expect("parse network");
+    assert_eq!(network.count_turns("AAA", |node| node == "ZZZ"), None);
+
+    let network = Day8::parse("L\n\nAAA = (ZZZ, AAA)\nZZZ = (ZZZ, ZZZ)").expect("parse network");
+    assert_eq!(Day8::part1(&network).to_string(), "1");
+}
@@ -0,0 +1,47 @@
+use common::{parse, ParseError, Solution};
+use sequence::{Int, Sequence};
+pub mod sequence;
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<Vec<Int>>;
+    type Output = Int;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                let values: Vec<Int> = line
+                    .split_whitespace()
+                    .map(|n| parse::number(i, line, n))
+                    .collect::<Result<_, _>>()?;
+                // it takes two values to have a difference to extrapolate from
+                match values.len() < 2 {
+                    true => Err(ParseError::in_line(
+                        i,
+                        line,
+                        line,
+                        "expected a sequence of at least two values, found",
+                    )),
+                    false => Ok(values),
+                }
+            })
+            .collect()
+    }
+
+    fn part1(input: &Self::Input) -> Self::Output {
+        input
+            .iter()
+            .map(|values| Sequence::new(values.clone()).extrapolate_next())
+            .sum()
+    }
+
+    fn part2(input: &Self::Input) -> Self::Output {
+        input
+            .iter()
+            .map(|values| Sequence::new(values.clone()).extrapolate_prev())
+            .sum()
+    }
+}
@@ -38,7 +38,11 @@ impl Sequence {
                 }
                 new_layer.push(diff);
             });
-        self.zero_layer_built = all_zero;
+        // a single value has no differences left, the ones it would have are taken as zero
+        self.zero_layer_built = all_zero || new_layer.len() < 2;
+        if new_layer.is_empty() {
+            return;
+        }
         self.seq_starting_vals.push(new_layer[0]);
         self.seq_final_vals
             .push(*new_layer.last().expect("has a value"));
@@ -49,7 +53,7 @@ impl Sequence {
         while !self.zero_layer_built {
             self.next_layer();
         }
-        return self.seq_final_vals.iter().rev().fold(0, |acc, n| acc + n);
+        return self.seq_final_vals.iter().rev().sum();
     }
 
     pub fn extrapolate_prev(&mut self) -> Int {
@@ -0,0 +1,40 @@
+use day_9::sequence::Sequence;
+
+#[test]
+fn extrapolates_next_value() {
+    assert_eq!(
+        Sequence::new(vec![0, 3, 6, 9, 12, 15]).extrapolate_next(),
+        18
+    );
+    assert_eq!(
+        Sequence::new(vec![1, 3, 6, 10, 15, 21]).extrapolate_next(),
+        28
+    );
+    assert_eq!(
+        Sequence::new(vec![10, 13, 16, 21, 30, 45]).extrapolate_next(),
+        68
+    );
+}
+
+#[test]
+fn extrapolates_previous_value() {
+    assert_eq!(
+        Sequence::new(vec![0, 3, 6, 9, 12, 15]).extrapolate_prev(),
+        -3
+    );
+    assert_eq!(
+        Sequence::new(vec![1, 3, 6, 10, 15, 21]).extrapolate_prev(),
+        0
+    );
+    assert_eq!(
+        Sequence::new(vec![10, 13, 16, 21, 30, 45]).extrapolate_prev(),
+        5
+    );
+}
+
+#[test]
+fn constant_sequence_extrapolates_to_itself() {
+    let mut seq = Sequence::new(vec![7, 7, 7]);
+    assert_eq!(seq.extrapolate_next(), 7);
+    assert_eq!(seq.extrapolate_prev(), 7);
+}
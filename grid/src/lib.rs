@@ -0,0 +1,242 @@
+mod point;
+pub use point::{Point, ADJACENT, ORTHOGONAL};
+
+use common::ParseError;
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+// a dense, row-major 2D grid
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    // `cells` are read row by row, `width` at a time
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Self {
+        let height = match width {
+            0 => 0,
+            _ => cells.len() / width,
+        };
+        assert!(
+            width * height == cells.len(),
+            "{} cells don't fill rows of {}",
+            cells.len(),
+            width
+        );
+        Self {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    // one row per line, `tile` turns each char into a cell or rejects it with `None`
+    pub fn parse(text: &str, tile: impl FnMut(char) -> Option<T>) -> Result<Self, ParseError> {
+        Self::parse_lines(text.lines().enumerate(), tile)
+    }
+
+    // same as `parse` for lines taken from a larger input, paired with their 0-based line index
+    // so errors point at the right line
+    pub fn parse_lines<'a>(
+        lines: impl IntoIterator<Item = (usize, &'a str)>,
+        mut tile: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut cells: Vec<T> = vec![];
+        let mut width: Option<usize> = None;
+
+        for (i, line) in lines {
+            let mut row_width = 0;
+            for (x, c) in line.chars().enumerate() {
+                match tile(c) {
+                    Some(cell) => cells.push(cell),
+                    None => return Err(ParseError::new(i + 1, x + 1, c, "unexpected tile")),
+                }
+                row_width += 1;
+            }
+            match width {
+                Some(width) if width != row_width => {
+                    return Err(ParseError::in_line(
+                        i,
+                        line,
+                        line,
+                        "row width differs from the first row's",
+                    ))
+                }
+                _ => width = Some(row_width),
+            }
+        }
+
+        Ok(Self::from_vec(width.unwrap_or(0), cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        p.x < self.width && p.y < self.height
+    }
+
+    pub fn get(&self, p: Point) -> Option<&T> {
+        match self.contains(p) {
+            true => Some(&self.cells[self.idx(p)]),
+            false => None,
+        }
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        match self.contains(p) {
+            true => {
+                let idx = self.idx(p);
+                Some(&mut self.cells[idx])
+            }
+            false => None,
+        }
+    }
+
+    fn idx(&self, p: Point) -> usize {
+        p.y * self.width + p.x
+    }
+
+    // the points `steps` away from `p` that are inside the grid
+    fn neighbors<'a>(
+        &'a self,
+        p: Point,
+        steps: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = Point> + 'a {
+        steps
+            .iter()
+            .filter_map(move |(dx, dy)| p.offset(*dx, *dy))
+            .filter(|n| self.contains(*n))
+    }
+
+    // left, right, up and down of `p`
+    pub fn neighbors4(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        self.neighbors(p, &ORTHOGONAL)
+    }
+
+    // every point touching `p`, diagonals included
+    pub fn neighbors8(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        self.neighbors(p, &ADJACENT)
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[(y * self.width)..((y + 1) * self.width)]
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.cells[(y * self.width)..((y + 1) * self.width)]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    // top to bottom
+    pub fn col(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(
+            x < self.width,
+            "column {} outside grid of width {}",
+            x,
+            self.width
+        );
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.col(x))
+    }
+
+    // every point in the grid, row by row
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    // the first point, row by row, whose cell satisfies `pred`
+    pub fn position(&self, mut pred: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.iter().find(|(_, cell)| pred(cell)).map(|(p, _)| p)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::from_vec(self.width, self.cells.iter().map(f).collect())
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Self::from_vec(width, vec![fill; width * height])
+    }
+
+    // rows become columns
+    pub fn transpose(&self) -> Self {
+        let cells = (0..self.width).flat_map(|x| self.col(x).cloned()).collect();
+        Self::from_vec(self.height, cells)
+    }
+
+    // a quarter turn clockwise, the bottom row becomes the first column
+    pub fn rotate_cw(&self) -> Self {
+        let cells = (0..self.width)
+            .flat_map(|x| self.col(x).rev().cloned())
+            .collect();
+        Self::from_vec(self.height, cells)
+    }
+
+    // a quarter turn counter-clockwise, the last column becomes the first row
+    pub fn rotate_ccw(&self) -> Self {
+        let cells = (0..self.width)
+            .rev()
+            .flat_map(|x| self.col(x).cloned())
+            .collect();
+        Self::from_vec(self.height, cells)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &Self::Output {
+        match self.get(p) {
+            Some(cell) => cell,
+            None => panic!("{} outside {}x{} grid", p, self.width, self.height),
+        }
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut Self::Output {
+        let (width, height) = (self.width, self.height);
+        match self.get_mut(p) {
+            Some(cell) => cell,
+            None => panic!("{} outside {}x{} grid", p, width, height),
+        }
+    }
+}
+
+// one line per row, cells written back to back
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
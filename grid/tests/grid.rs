@@ -0,0 +1,52 @@
+use grid::{Grid, Point};
+
+fn letters() -> Grid<char> {
+    Grid::parse("abc\ndef", Some).expect("parse grid")
+}
+
+#[test]
+fn parses_rows_of_text() {
+    let grid = letters();
+
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid[Point::new(2, 1)], 'f');
+    assert_eq!(grid.get(Point::new(3, 0)), None);
+    assert_eq!(grid.to_string(), "abc\ndef");
+}
+
+#[test]
+fn parse_reports_bad_tiles_and_ragged_rows() {
+    let err = Grid::parse("..\n.x", |c| (c == '.').then_some(())).unwrap_err();
+    assert_eq!((err.line(), err.column(), err.token()), (2, 2, "x"));
+
+    let err = Grid::parse("abc\nde", Some).unwrap_err();
+    assert_eq!((err.line(), err.column(), err.token()), (2, 1, "de"));
+}
+
+#[test]
+fn neighbors_stay_in_bounds() {
+    let grid = letters();
+
+    let corner: Vec<Point> = grid.neighbors4(Point::new(0, 0)).collect();
+    assert_eq!(corner, vec![Point::new(1, 0), Point::new(0, 1)]);
+    assert_eq!(grid.neighbors8(Point::new(1, 0)).count(), 5);
+}
+
+#[test]
+fn row_and_column_views() {
+    let grid = letters();
+
+    assert_eq!(grid.row(1), &['d', 'e', 'f']);
+    assert_eq!(grid.col(1).collect::<String>(), "be");
+    assert_eq!(grid.cols().count(), 3);
+}
+
+#[test]
+fn transposes_and_rotates() {
+    let grid = letters();
+
+    assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+    assert_eq!(grid.rotate_cw().to_string(), "da\neb\nfc");
+    assert_eq!(grid.rotate_ccw().to_string(), "cf\nbe\nad");
+    assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+}